@@ -8,7 +8,7 @@ export OMPI_MCA_rmaps_base_oversubscribe=1
 EXTRA_CARGO_FLAGS=""
 if test "$TRAVIS_OS_NAME" == "windows";
 then
-  EXTRA_CARGO_FLAGS="--features derive"
+  EXTRA_CARGO_FLAGS="--features bitvec,cuda,derive,half,serde,testing,trace"
 else
   EXTRA_CARGO_FLAGS="--all-features"
 fi
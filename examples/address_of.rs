@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{address_of, UserDatatype};
+use mpi::ffi::MPI_Aint;
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::align_of;
+
+#[repr(C)]
+#[derive(Default, Debug, PartialEq)]
+struct Particle {
+    id: i32,
+    mass: f64,
+}
+
+unsafe impl Equivalence for Particle {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        // Take the displacements from the addresses of an instance on the stack.
+        let particle = Particle::default();
+        let base = address_of(&particle);
+        UserDatatype::structured(
+            &[1, 1],
+            &[
+                address_of(&particle.id) - base,
+                address_of(&particle.mass) - base,
+            ],
+            &[i32::equivalent_datatype(), f64::equivalent_datatype()],
+        )
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_process = world.process_at_rank(0);
+
+    let particle = Particle::default();
+    let base = address_of(&particle);
+    assert_eq!(base, address_of(&particle.id));
+    // `mass` is padded to the alignment of `f64`.
+    assert_eq!(
+        Address(align_of::<f64>() as MPI_Aint),
+        address_of(&particle.mass) - base
+    );
+
+    let mut particle = if world.rank() == 0 {
+        Particle { id: 7, mass: 1.5 }
+    } else {
+        Particle::default()
+    };
+    root_process.broadcast_into(&mut particle);
+    assert_eq!(Particle { id: 7, mass: 1.5 }, particle);
+}
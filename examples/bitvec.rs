@@ -0,0 +1,63 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use bitvec::prelude::*;
+use mpi::traits::*;
+
+const LEN: usize = 1000;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_process = world.process_at_rank(0);
+
+    // A mask of the squares below `LEN`, whose length is not a multiple of the word size
+    let mut squares = BitVec::<usize, Lsb0>::repeat(false, LEN);
+    for k in (0..).take_while(|k| k * k < LEN) {
+        squares.set(k * k, true);
+    }
+    assert_eq!(squares.count_ones(), 32);
+
+    if rank == 0 {
+        for destination in 1..size {
+            world.process_at_rank(destination).send_bitvec(&mut squares);
+        }
+    } else {
+        let (mask, status) = root_process.receive_bitvec::<Lsb0>();
+        assert_eq!(status.source_rank(), 0);
+        assert_eq!(mask.len(), LEN);
+        assert_eq!(mask, squares);
+        assert!(mask[961] && !mask[962]);
+    }
+
+    // A copy of a slice starting in the middle of a word keeps that offset, it is realigned
+    // before it is sent.
+    let mut tail = BitVec::<usize, Lsb0>::from_bitslice(&squares[100..]);
+    if rank == 0 {
+        for destination in 1..size {
+            world.process_at_rank(destination).send_bitvec(&mut tail);
+        }
+        assert_eq!(tail.len(), LEN - 100);
+    } else {
+        let (mask, _) = root_process.receive_bitvec::<Lsb0>();
+        assert_eq!(mask, tail);
+        assert_eq!(mask.count_ones(), 22);
+        assert!(mask[0] && mask[21] && !mask[1]);
+    }
+
+    // Empty masks and other bit orders work as well.
+    let mut empty = BitVec::<usize, Msb0>::repeat(false, 0);
+    if rank == 0 {
+        for destination in 1..size {
+            world
+                .process_at_rank(destination)
+                .send_bitvec_with_tag(&mut empty, 3);
+        }
+    } else {
+        let (mask, status) = root_process.receive_bitvec_with_tag::<Msb0>(3);
+        assert_eq!(status.tag(), 3);
+        assert!(mask.is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Count;
+use std::mem::size_of;
+
+const GLOBAL: usize = 11;
+const BLOCK: usize = 2;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let this_process = world.process_at_rank(world.rank());
+
+    // Mark the elements selected for every rank with that rank.
+    let mut global = [-1; GLOBAL];
+    for rank in 0..size {
+        let t = UserDatatype::block_cyclic(
+            GLOBAL as Count,
+            BLOCK as Count,
+            size,
+            rank,
+            &i32::equivalent_datatype(),
+        );
+        let local = vec![rank; t.size() as usize / size_of::<i32>()];
+        let mut v = unsafe { MutView::with_count_and_datatype(&mut global[..], 1, &t) };
+        p2p::send_receive_into(&local[..], &this_process, &mut v, &this_process);
+    }
+
+    // The selections tile the global sequence.
+    let expected = (0..GLOBAL)
+        .map(|i| ((i / BLOCK) % size as usize) as i32)
+        .collect::<Vec<_>>();
+    assert_eq!(&expected[..], &global[..]);
+}
@@ -0,0 +1,70 @@
+#![deny(warnings)]
+#[macro_use]
+extern crate mpi;
+
+use mpi::datatype::{address_of, BottomBuffer, UncommittedDatatypeRef, UserDatatype};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+static STEP: i32 = 42;
+static TIME: f64 = 2.5;
+static POSITION: [f64; 3] = [1.0, 2.0, 3.0];
+
+#[repr(C)]
+#[derive(Default, Debug, PartialEq)]
+struct Snapshot {
+    step: i32,
+    time: f64,
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+unsafe impl Equivalence for Snapshot {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        struct_datatype!(Snapshot {
+            step,
+            time,
+            x,
+            y,
+            z
+        })
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    // The globals are sent as a single message without copying them into one buffer first.
+    let globals = UserDatatype::structured(
+        &[1, 1, 3],
+        &[
+            address_of(&STEP),
+            address_of(&TIME),
+            address_of(&POSITION[..]),
+        ],
+        &[
+            UncommittedDatatypeRef::from(i32::equivalent_datatype()),
+            UncommittedDatatypeRef::from(f64::equivalent_datatype()),
+            UncommittedDatatypeRef::from(f64::equivalent_datatype()),
+        ],
+    );
+    let send = unsafe { BottomBuffer::with_count_and_datatype(1, &globals) };
+
+    let mut snapshot = Snapshot::default();
+    p2p::send_receive_into(&send, &this_process, &mut snapshot, &this_process);
+
+    assert_eq!(
+        Snapshot {
+            step: STEP,
+            time: TIME,
+            x: POSITION[0],
+            y: POSITION[1],
+            z: POSITION[2],
+        },
+        snapshot
+    );
+}
@@ -0,0 +1,25 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_process = world.process_at_rank(0);
+
+    let mut counters: Vec<AtomicI64> = (0..4).map(|_| AtomicI64::new(0)).collect();
+    if world.rank() == 0 {
+        for (i, counter) in counters.iter().enumerate() {
+            counter.fetch_add(i as i64 + 1, Ordering::Relaxed);
+        }
+    }
+
+    // Borrowing the counters mutably ensures that no other thread accesses them while they are
+    // broadcast.
+    root_process.broadcast_into(counters.as_integers_mut());
+
+    let values: Vec<i64> = counters.iter().map(|c| c.load(Ordering::Relaxed)).collect();
+    assert_eq!(vec![1, 2, 3, 4], values);
+}
@@ -0,0 +1,31 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let values = [1.5f64, -2.25, std::f64::consts::PI, 1e300];
+    let bytes = values[..].as_byte_buffer();
+    assert_eq!(bytes.count(), 4 * 8);
+
+    let mut received = [0.0f64; 4];
+    {
+        let mut received_bytes = unsafe { received.as_byte_buffer_mut() };
+        p2p::send_receive_into(&bytes, &this_process, &mut received_bytes, &this_process);
+    }
+    assert_eq!(values, received);
+
+    let mut raw = [0u8; 8];
+    p2p::send_receive_into(
+        &1e300f64.as_byte_buffer(),
+        &this_process,
+        &mut raw,
+        &this_process,
+    );
+    assert_eq!(raw, 1e300f64.to_ne_bytes());
+}
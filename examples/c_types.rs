@@ -0,0 +1,53 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use std::mem::size_of;
+use std::os::raw::{c_char, c_double, c_int, c_long, c_uint, c_ulong};
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    assert_eq!(
+        c_int::equivalent_datatype().size() as usize,
+        size_of::<c_int>()
+    );
+    assert_eq!(
+        c_uint::equivalent_datatype().size() as usize,
+        size_of::<c_uint>()
+    );
+    assert_eq!(
+        c_long::equivalent_datatype().size() as usize,
+        size_of::<c_long>()
+    );
+    assert_eq!(
+        c_ulong::equivalent_datatype().size() as usize,
+        size_of::<c_ulong>()
+    );
+    assert_eq!(
+        c_char::equivalent_datatype().size() as usize,
+        size_of::<c_char>()
+    );
+    assert_eq!(
+        c_double::equivalent_datatype().size() as usize,
+        size_of::<c_double>()
+    );
+
+    let ints: [c_int; 4] = [1, -2, 3, c_int::MAX];
+    let mut received = [0 as c_int; 4];
+    p2p::send_receive_into(&ints[..], &this_process, &mut received[..], &this_process);
+    assert_eq!(ints, received);
+
+    let longs: Vec<c_long> = vec![c_long::MIN, 0, c_long::MAX];
+    let mut received = vec![0 as c_long; 3];
+    p2p::send_receive_into(&longs[..], &this_process, &mut received[..], &this_process);
+    assert_eq!(longs, received);
+
+    let chars = b"rsmpi".map(|c| c as c_char);
+    let mut received = [0 as c_char; 5];
+    p2p::send_receive_into(&chars, &this_process, &mut received, &this_process);
+    assert_eq!(chars, received);
+}
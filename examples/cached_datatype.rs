@@ -0,0 +1,42 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{cached_datatype, DatatypeRef, UserDatatype};
+use mpi::traits::*;
+
+struct Triple([f64; 3]);
+
+unsafe impl Equivalence for Triple {
+    type Out = DatatypeRef<'static>;
+    fn equivalent_datatype() -> Self::Out {
+        cached_datatype::<Self, _>(|| UserDatatype::contiguous(3, &f64::equivalent_datatype()))
+    }
+}
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(mpi::Threading::Multiple).unwrap();
+    let world = universe.world();
+
+    let handle = format!("{:?}", Triple::equivalent_datatype().as_raw());
+    assert_eq!(
+        handle,
+        format!("{:?}", Triple::equivalent_datatype().as_raw())
+    );
+
+    if threading == mpi::Threading::Multiple {
+        let threads: Vec<_> = (0..2)
+            .map(|_| std::thread::spawn(|| format!("{:?}", Triple::equivalent_datatype().as_raw())))
+            .collect();
+
+        for thread in threads {
+            assert_eq!(handle, thread.join().unwrap());
+        }
+    }
+
+    let mut triple = Triple([0.0; 3]);
+    if world.rank() == 0 {
+        triple.0 = [1.0, 2.0, 3.0];
+    }
+    world.process_at_rank(0).broadcast_into(&mut triple);
+    assert_eq!(triple.0, [1.0, 2.0, 3.0]);
+}
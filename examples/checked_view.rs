@@ -0,0 +1,38 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{OutOfBoundsError, UserDatatype, View};
+use mpi::ffi::MPI_Aint;
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::size_of;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.process_at_rank(world.rank());
+
+    // The first two out of every three elements, which describes nothing but initialized `i32`s
+    // and is therefore fine to view any `[i32]` through
+    let t = UserDatatype::vector(1, 2, 3, &i32::equivalent_datatype());
+    let t = UserDatatype::resized(&t, Address(0), 3 * Address(size_of::<i32>() as MPI_Aint));
+
+    let b1 = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // The third instance touches the seventh and eighth element, but there are only seven.
+    assert_eq!(
+        Err(OutOfBoundsError {
+            count: 3,
+            size: 7 * size_of::<i32>()
+        }),
+        unsafe { View::try_with_count_and_datatype(&b1[..7], 3, &t) }.map(|_| ())
+    );
+    assert!(unsafe { View::try_with_count_and_datatype(&b1[..], -1, &t) }.is_err());
+
+    // The extent of the third instance reaches beyond the buffer, but not the data it describes.
+    let v = unsafe { View::try_with_count_and_datatype(&b1[..], 3, &t) }.unwrap();
+    let mut b2 = [0; 6];
+    p2p::send_receive_into(&v, &this_process, &mut b2[..], &this_process);
+    assert_eq!([1, 2, 4, 5, 7, 8], b2);
+}
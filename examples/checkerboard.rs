@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{CellColor, MutView, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+const NX: usize = 5;
+const NY: usize = 3;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    // A row-major grid of NY rows of NX cells holding their index
+    let grid = (0..(NX * NY) as i32).collect::<Vec<_>>();
+
+    let red = UserDatatype::checkerboard(
+        NX as i32,
+        NY as i32,
+        CellColor::Red,
+        &i32::equivalent_datatype(),
+    );
+    let black = UserDatatype::checkerboard(
+        NX as i32,
+        NY as i32,
+        CellColor::Black,
+        &i32::equivalent_datatype(),
+    );
+    assert_eq!(red.size(), 8 * 4);
+    assert_eq!(black.size(), 7 * 4);
+
+    let mut red_cells = [0; 8];
+    {
+        let v = unsafe { View::with_count_and_datatype(&grid[..], 1, &red) };
+        p2p::send_receive_into(&v, &this_process, &mut red_cells[..], &this_process);
+    }
+    assert_eq!(red_cells, [0, 2, 4, 6, 8, 10, 12, 14]);
+
+    // Update only the black cells of a grid in place.
+    let mut updated = grid.clone();
+    {
+        let black_cells = [-1; 7];
+        let mut v = unsafe { MutView::with_count_and_datatype(&mut updated[..], 1, &black) };
+        p2p::send_receive_into(&black_cells[..], &this_process, &mut v, &this_process);
+    }
+    for y in 0..NY {
+        for x in 0..NX {
+            let i = y * NX + x;
+            let expected = if (x + y) % 2 == 0 { i as i32 } else { -1 };
+            assert_eq!(updated[i], expected);
+        }
+    }
+}
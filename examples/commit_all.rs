@@ -0,0 +1,32 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{commit_all, UncommittedUserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.process_at_rank(world.rank());
+
+    let types = commit_all(vec![
+        UncommittedUserDatatype::contiguous(2, &i32::equivalent_datatype()),
+        UncommittedUserDatatype::vector(2, 1, 2, &i32::equivalent_datatype()),
+        UncommittedUserDatatype::indexed(&[1, 2], &[0, 2], &i32::equivalent_datatype()),
+    ])
+    .expect("committing valid datatypes succeeds");
+    assert_eq!(3, types.len());
+
+    let data = [1, 2, 3, 4];
+    let expected = [vec![1, 2], vec![1, 3], vec![1, 3, 4]];
+
+    for (t, expected) in types.iter().zip(expected.iter()) {
+        let mut received = vec![0; expected.len()];
+        {
+            let v = unsafe { View::with_count_and_datatype(&data[..], 1, t) };
+            p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+        }
+        assert_eq!(expected, &received);
+    }
+}
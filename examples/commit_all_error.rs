@@ -0,0 +1,38 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{commit_all, UncommittedUserDatatype};
+use mpi::ffi;
+use mpi::raw::AsRaw;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // Let MPI report errors instead of aborting.
+    unsafe {
+        ffi::MPI_Comm_set_errhandler(world.as_raw(), ffi::RSMPI_ERRORS_RETURN);
+        ffi::MPI_Comm_set_errhandler(ffi::RSMPI_COMM_SELF, ffi::RSMPI_ERRORS_RETURN);
+    }
+
+    // The batch stops at the third datatype, the ones before and after it are freed.
+    let result = commit_all(vec![
+        UncommittedUserDatatype::contiguous(2, &i32::equivalent_datatype()),
+        UncommittedUserDatatype::vector(2, 1, 2, &i32::equivalent_datatype()),
+        UncommittedUserDatatype::uncommittable(),
+        UncommittedUserDatatype::contiguous(3, &f64::equivalent_datatype()),
+    ]);
+    match result {
+        Err((index, error)) => {
+            assert_eq!(2, index);
+            assert_ne!(error.code, ffi::MPI_SUCCESS as i32);
+        }
+        Ok(_) => panic!("committing MPI_DATATYPE_NULL succeeded"),
+    }
+
+    unsafe {
+        ffi::MPI_Comm_set_errhandler(world.as_raw(), ffi::RSMPI_ERRORS_ARE_FATAL);
+        ffi::MPI_Comm_set_errhandler(ffi::RSMPI_COMM_SELF, ffi::RSMPI_ERRORS_ARE_FATAL);
+    }
+}
@@ -5,6 +5,7 @@ extern crate mpi;
 #[macro_use]
 extern crate memoffset;
 
+use mpi::ffi::MPI_Aint;
 use mpi::{
     datatype::{UncommittedUserDatatype, UserDatatype},
     traits::*,
@@ -27,9 +28,9 @@ unsafe impl Equivalence for ComplexDatatype {
         UserDatatype::structured(
             &[1, 1, 1],
             &[
-                offset_of!(ComplexDatatype, b) as Address,
-                offset_of!(ComplexDatatype, ints) as Address,
-                offset_of!(ComplexDatatype, tuple) as Address,
+                Address(offset_of!(ComplexDatatype, b) as MPI_Aint),
+                Address(offset_of!(ComplexDatatype, ints) as MPI_Aint),
+                Address(offset_of!(ComplexDatatype, tuple) as MPI_Aint),
             ],
             &[
                 bool::equivalent_datatype().into(),
@@ -37,8 +38,8 @@ unsafe impl Equivalence for ComplexDatatype {
                 UncommittedUserDatatype::structured(
                     &[2, 1],
                     &[
-                        offset_of!(TupleType, 0) as Address,
-                        offset_of!(TupleType, 1) as Address,
+                        Address(offset_of!(TupleType, 0) as MPI_Aint),
+                        Address(offset_of!(TupleType, 1) as MPI_Aint),
                     ],
                     &[f32::equivalent_datatype(), u8::equivalent_datatype()],
                 )
@@ -0,0 +1,56 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype, View};
+use mpi::traits::*;
+use mpi::Count;
+
+const N: usize = 4;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let root_process = world.process_at_rank(0);
+
+    // Real parts followed by imaginary parts.
+    let split: Vec<f64> = (0..N)
+        .map(|i| i as f64)
+        .chain((0..N).map(|i| -(i as f64)))
+        .collect();
+
+    let split_type = UserDatatype::split_complex::<f64>(N as Count);
+    let interleaved_type = UserDatatype::interleaved_complex::<f64>();
+
+    if rank == 0 {
+        let v = unsafe { View::with_count_and_datatype(&split[..], 1, &split_type) };
+        for destination in 1..world.size() {
+            world.process_at_rank(destination).send(&v);
+        }
+    } else if rank % 2 == 1 {
+        // Reconstruct in interleaved storage.
+        let mut interleaved = vec![0.0f64; 2 * N];
+        {
+            let mut v = unsafe {
+                MutView::with_count_and_datatype(
+                    &mut interleaved[..],
+                    N as Count,
+                    &interleaved_type,
+                )
+            };
+            root_process.receive_into(&mut v);
+        }
+        let expected: Vec<f64> = (0..N).flat_map(|i| vec![i as f64, -(i as f64)]).collect();
+        assert_eq!(expected, interleaved);
+    } else {
+        // Receive back into split storage.
+        let mut received = vec![0.0f64; 2 * N];
+        {
+            let mut v =
+                unsafe { MutView::with_count_and_datatype(&mut received[..], 1, &split_type) };
+            root_process.receive_into(&mut v);
+        }
+        assert_eq!(split, received);
+    }
+}
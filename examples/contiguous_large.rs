@@ -0,0 +1,23 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::UserDatatype;
+use mpi::traits::*;
+use mpi::{Count, LargeCount};
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    // Counts within the range of `Count` produce a plain contiguous datatype.
+    let small = UserDatatype::contiguous_large(5, &u8::equivalent_datatype());
+    assert_eq!(5, small.size_x());
+    assert_eq!(5, small.size());
+
+    // The datatype is only described, no buffer of this size is allocated.
+    let count = 3 * LargeCount::from(Count::MAX) + 5;
+    let large = UserDatatype::contiguous_large(count, &u8::equivalent_datatype());
+    assert_eq!(count, large.size_x());
+
+    let large = UserDatatype::contiguous_large(count, &u16::equivalent_datatype());
+    assert_eq!(2 * count, large.size_x());
+}
@@ -0,0 +1,61 @@
+#![deny(warnings)]
+#[macro_use]
+extern crate memoffset;
+
+extern crate mpi;
+
+use mpi::datatype::{BottomBuffer, UserDatatype};
+use mpi::ffi::MPI_Aint;
+use mpi::traits::*;
+use mpi::Address;
+
+/// A single row with two non-zero elements, received in one piece
+#[repr(C)]
+#[derive(Default)]
+struct Row {
+    indices: [i32; 2],
+    values: [f64; 2],
+}
+
+unsafe impl Equivalence for Row {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        UserDatatype::structured(
+            &[2, 2],
+            &[
+                Address(offset_of!(Row, indices) as MPI_Aint),
+                Address(offset_of!(Row, values) as MPI_Aint),
+            ],
+            &[i32::equivalent_datatype(), f64::equivalent_datatype()],
+        )
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_process = world.process_at_rank(0);
+
+    // 3 x 4 sparse matrix
+    // [ 1 0 2 0 ]
+    // [ 0 3 0 4 ]
+    // [ 0 0 0 5 ]
+    let row_offsets = [0, 2, 4, 5];
+    let indices = [0, 2, 1, 3, 3];
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+    if world.rank() == 0 {
+        // The datatype holds the absolute addresses of the row in `indices` and `values`.
+        let t = UserDatatype::csr_row(&row_offsets, &indices, &values, 1);
+        let v = unsafe { BottomBuffer::with_count_and_datatype(1, &t) };
+        for destination in 1..world.size() {
+            world.process_at_rank(destination).send(&v);
+        }
+    } else {
+        let mut row = Row::default();
+        root_process.receive_into(&mut row);
+
+        assert_eq!([1, 3], row.indices);
+        assert_eq!([3.0, 4.0], row.values);
+    }
+}
@@ -0,0 +1,128 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{
+    Combiner, DecodedDatatype, OverlapError, UncommittedDatatypeRef, UncommittedUserDatatype,
+    UserDatatype,
+};
+use mpi::ffi::MPI_Aint;
+use mpi::raw::AsRaw;
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::size_of;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    assert_eq!(
+        Combiner::Named,
+        f64::equivalent_datatype().envelope().combiner
+    );
+    assert!(f64::equivalent_datatype().contents().is_none());
+
+    let t = UserDatatype::contiguous(3, &i32::equivalent_datatype());
+    assert!(f64::equivalent_datatype().is_predefined());
+    assert!(!t.is_predefined());
+    let contents = t.contents().unwrap();
+    assert_eq!(Combiner::Contiguous, contents.combiner);
+    assert_eq!(vec![3], contents.integers);
+    assert!(contents.addresses.is_empty());
+    match contents.datatypes[..] {
+        [DecodedDatatype::Predefined(_)] => {}
+        _ => panic!("expected a single predefined datatype"),
+    }
+
+    let int_size = Address(size_of::<i32>() as MPI_Aint);
+
+    let disjoint = UserDatatype::structured(
+        &[2, 1],
+        &[Address(0), 2 * int_size],
+        &[i32::equivalent_datatype(), i32::equivalent_datatype()],
+    );
+    assert_eq!(Ok(()), disjoint.validate_non_overlapping());
+
+    // The second block starts inside the first one.
+    let overlapping = UserDatatype::structured(
+        &[2, 1, 1],
+        &[Address(0), 4 * int_size, int_size],
+        &[
+            i32::equivalent_datatype(),
+            i32::equivalent_datatype(),
+            i32::equivalent_datatype(),
+        ],
+    );
+    assert_eq!(
+        Err(OverlapError {
+            first: 0,
+            second: 2
+        }),
+        overlapping.validate_non_overlapping()
+    );
+
+    let indexed = UserDatatype::indexed(&[2, 2], &[0, 1], &i32::equivalent_datatype());
+    assert_eq!(
+        Err(OverlapError {
+            first: 0,
+            second: 1
+        }),
+        indexed.validate_non_overlapping()
+    );
+
+    // Consecutive elements of the only block overlap, as its extent is shorter than the data.
+    let pair = UserDatatype::contiguous(2, &i32::equivalent_datatype());
+    let shifted = UserDatatype::resized(&pair, Address(0), int_size);
+    let self_overlapping = UserDatatype::indexed(&[2], &[0], &shifted);
+    assert_eq!(
+        Err(OverlapError {
+            first: 0,
+            second: 0
+        }),
+        self_overlapping.validate_non_overlapping()
+    );
+    let spread = UserDatatype::indexed(&[1, 1], &[0, 2], &shifted);
+    assert_eq!(Ok(()), spread.validate_non_overlapping());
+
+    // Combiners this crate does not know are reported as such instead of panicking.
+    assert_eq!(Combiner::Unknown(-1), Combiner::from(-1));
+
+    // The first two blocks are adjacent and merged, the empty block is dropped.
+    let coalesced =
+        UserDatatype::indexed_coalesced(&[2, 3, 0, 1], &[0, 2, 6, 7], &i32::equivalent_datatype());
+    let contents = coalesced.contents().unwrap();
+    assert_eq!(Combiner::Indexed, contents.combiner);
+    assert_eq!(vec![2, 5, 1, 0, 7], contents.integers);
+    assert_eq!(6 * int_size, Address::from(coalesced.size()));
+
+    let nested = UserDatatype::vector(
+        2,
+        1,
+        3,
+        &UncommittedUserDatatype::contiguous(2, &f64::equivalent_datatype()),
+    );
+    assert_eq!("UserDatatype(Vector)", format!("{:?}", nested));
+    assert_eq!(
+        concat!(
+            "Vector { integers: [2, 1, 3], addresses: [] }\n",
+            "  Contiguous { integers: [2], addresses: [] }\n",
+            "    MPI_DOUBLE"
+        ),
+        format!("{:#?}", nested)
+    );
+
+    assert!(f64::equivalent_datatype().type_name().contains("DOUBLE"));
+    assert_eq!("Vector", nested.type_name());
+
+    let base_type = nested.base_type().unwrap();
+    assert_eq!(f64::equivalent_datatype().as_raw(), base_type.as_raw());
+    assert!(disjoint.base_type().is_some());
+    let mixed = UserDatatype::structured(
+        &[1, 1],
+        &[Address(0), int_size],
+        &[i32::equivalent_datatype(), f64::equivalent_datatype()],
+    );
+    assert!(mixed.base_type().is_none());
+
+    assert!(UncommittedDatatypeRef::null().is_null());
+    assert!(!f64::equivalent_datatype().is_null());
+    assert!(!coalesced.is_null());
+}
@@ -1,7 +1,7 @@
 #![deny(warnings)]
 extern crate mpi;
 
-use mpi::datatype::DynBufferMut;
+use mpi::datatype::{Combiner, DynBufferMut, SystemDatatype};
 use mpi::traits::*;
 
 fn main() {
@@ -24,4 +24,20 @@ fn main() {
     root_process.broadcast_into(&mut buffer);
 
     assert_eq!([1, 2, 3, 4], ints);
+
+    let double_type = SystemDatatype::f64().to_user();
+    assert_eq!(8, double_type.size());
+    assert_eq!(Combiner::Dup, double_type.envelope().combiner);
+    assert!(!double_type.is_predefined());
+
+    let mut doubles = if world.rank() == 0 {
+        [0.5f64, 1.5]
+    } else {
+        [0.0; 2]
+    };
+    let mut buffer = unsafe {
+        DynBufferMut::from_raw(doubles.as_mut_ptr(), doubles.count(), double_type.as_ref())
+    };
+    root_process.broadcast_into(&mut buffer);
+    assert_eq!([0.5, 1.5], doubles);
 }
@@ -0,0 +1,57 @@
+#![deny(warnings)]
+#[macro_use]
+extern crate mpi;
+
+#[macro_use]
+extern crate memoffset;
+
+use mpi::ffi::MPI_Aint;
+use mpi::{
+    datatype::{StructTypeBuilder, UserDatatype},
+    traits::*,
+    Address,
+};
+use std::panic;
+
+#[repr(C)]
+struct Sample {
+    id: u64,
+    value: f64,
+}
+
+unsafe impl Equivalence for Sample {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        StructTypeBuilder::new()
+            .field::<u64>(Address(offset_of!(Sample, id) as MPI_Aint))
+            .field::<f64>(Address(offset_of!(Sample, value) as MPI_Aint))
+            .build()
+    }
+}
+
+// An implementation that has drifted: the `value` field is missing.
+#[allow(dead_code)]
+#[repr(C)]
+struct Drifted {
+    id: u64,
+    value: f64,
+}
+
+unsafe impl Equivalence for Drifted {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        StructTypeBuilder::new()
+            .field::<u64>(Address(offset_of!(Drifted, id) as MPI_Aint))
+            .build()
+    }
+}
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    assert_datatype_size!(f64);
+    assert_datatype_size!(Sample);
+
+    let result = panic::catch_unwind(|| assert_datatype_size!(Drifted));
+    assert!(result.is_err());
+}
@@ -0,0 +1,98 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{cached_datatype, DatatypeRef, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::topology::SystemCommunicator;
+use mpi::traits::*;
+use mpi::Tag;
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+const THREADS: usize = 8;
+
+struct Quad([i32; 4]);
+
+unsafe impl Equivalence for Quad {
+    type Out = DatatypeRef<'static>;
+    fn equivalent_datatype() -> Self::Out {
+        cached_datatype::<Self, _>(|| UserDatatype::contiguous(4, &i32::equivalent_datatype()))
+    }
+}
+
+fn main() {
+    let (_universe, threading) = mpi::initialize_with_threading(mpi::Threading::Multiple).unwrap();
+
+    if threading != mpi::Threading::Multiple {
+        // Silently return - MPI implementation may not support `threading::Multiple`
+        return;
+    }
+
+    let barrier = Arc::new(Barrier::new(THREADS));
+
+    let threads: Vec<_> = (0..THREADS)
+        .map(|i| {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                // Release all threads at once so that they race to construct the cached datatype
+                // and their own datatypes.
+                barrier.wait();
+                let cached = format!("{:?}", Quad::equivalent_datatype().as_raw());
+                let every_other = UserDatatype::vector(2, 1, 2, &i32::equivalent_datatype());
+                (i, cached, every_other)
+            })
+        })
+        .collect();
+
+    let results: Vec<_> = threads
+        .into_iter()
+        .map(|thread| thread.join().unwrap())
+        .collect();
+
+    // All threads got the same cached datatype.
+    let handle = format!("{:?}", Quad::equivalent_datatype().as_raw());
+    for (_, cached, _) in &results {
+        assert_eq!(handle, *cached);
+    }
+
+    // Use the datatypes constructed on the other threads concurrently.
+    let threads: Vec<_> = results
+        .into_iter()
+        .map(|(i, _, every_other)| {
+            thread::spawn(move || {
+                let world = SystemCommunicator::world();
+                let this_process = world.this_process();
+                let tag = i as Tag;
+                let value = i as i32;
+
+                let quad = Quad([value; 4]);
+                let mut received = Quad([-1; 4]);
+                p2p::send_receive_into_with_tags(
+                    &quad,
+                    &this_process,
+                    tag,
+                    &mut received,
+                    &this_process,
+                    tag,
+                );
+                assert_eq!(received.0, [value; 4]);
+
+                let values = [value, -1, value + 1, -1];
+                let mut received = [0; 2];
+                let v = unsafe { View::with_count_and_datatype(&values[..], 1, &every_other) };
+                p2p::send_receive_into_with_tags(
+                    &v,
+                    &this_process,
+                    tag,
+                    &mut received[..],
+                    &this_process,
+                    tag,
+                );
+                assert_eq!(received, [value, value + 1]);
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
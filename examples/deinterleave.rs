@@ -0,0 +1,47 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Count;
+
+const COUNT: Count = 4;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.process_at_rank(world.rank());
+
+    // Interleaved (x, y) pairs
+    let pairs: [f64; 2 * COUNT as usize] = [0.0, 10.0, 1.0, 11.0, 2.0, 12.0, 3.0, 13.0];
+
+    // Each field is sent from the pairs and received into its own array of `soa`, with the
+    // array of `y` starting `COUNT` elements after that of `x`.
+    let mut soa = [-1.0f64; 2 * COUNT as usize];
+    for field in 0..2 {
+        let send_type = UserDatatype::every_nth(2, field, COUNT, &f64::equivalent_datatype());
+        let receive_type =
+            UserDatatype::deinterleave(2, field, COUNT, COUNT, &f64::equivalent_datatype());
+        let v = unsafe { View::with_count_and_datatype(&pairs[..], 1, &send_type) };
+        let mut w = unsafe { MutView::with_count_and_datatype(&mut soa[..], 1, &receive_type) };
+        p2p::send_receive_into(&v, &this_process, &mut w, &this_process);
+    }
+
+    let (xs, ys) = soa.split_at(COUNT as usize);
+    assert_eq!([0.0, 1.0, 2.0, 3.0], xs);
+    assert_eq!([10.0, 11.0, 12.0, 13.0], ys);
+
+    // Receiving only `y` leaves the array of `x` untouched.
+    let mut ys_only = [-1.0f64; 2 * COUNT as usize];
+    {
+        let send_type = UserDatatype::every_nth(2, 1, COUNT, &f64::equivalent_datatype());
+        let receive_type =
+            UserDatatype::deinterleave(2, 1, COUNT, COUNT, &f64::equivalent_datatype());
+        let v = unsafe { View::with_count_and_datatype(&pairs[..], 1, &send_type) };
+        let mut w = unsafe { MutView::with_count_and_datatype(&mut ys_only[..], 1, &receive_type) };
+        p2p::send_receive_into(&v, &this_process, &mut w, &this_process);
+    }
+    assert_eq!([-1.0, -1.0, -1.0, -1.0], ys_only[..COUNT as usize]);
+    assert_eq!([10.0, 11.0, 12.0, 13.0], ys_only[COUNT as usize..]);
+}
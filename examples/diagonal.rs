@@ -0,0 +1,37 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::topology::Rank;
+use mpi::traits::*;
+use mpi::Count;
+
+const N: usize = 3;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = if rank > 0 { rank - 1 } else { size - 1 };
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Row-major N x N matrix
+    let matrix = (0..(N * N) as Rank)
+        .map(|x| rank * 100 + x)
+        .collect::<Vec<_>>();
+    let mut diagonal = [-1; N];
+
+    let t = UserDatatype::diagonal(N as Count, &Rank::equivalent_datatype());
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], 1, &t) };
+        p2p::send_receive_into(&v, &next_process, &mut diagonal[..], &previous_process);
+    }
+
+    let offset = previous_rank * 100;
+    assert_eq!([offset, offset + 4, offset + 8], diagonal);
+}
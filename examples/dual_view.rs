@@ -0,0 +1,31 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{DualView, UserDatatype};
+use mpi::point_to_point as p2p;
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let this_process = world.process_at_rank(rank);
+
+    let b1 = (1..).map(|x| rank * x).take(6).collect::<Vec<_>>();
+    let mut b2 = vec![-1; 6];
+
+    let t = UserDatatype::vector(2, 2, 3, &Rank::equivalent_datatype());
+    {
+        let mut dual = unsafe { DualView::with_count_and_datatype(&b1[..], &mut b2[..], 1, &t) };
+        let (send, mut receive) = dual.split();
+        p2p::send_receive_into(&send, &this_process, &mut receive, &this_process);
+    }
+
+    let b3 = (1..)
+        .map(|x| if x % 3 == 0 { -1 } else { rank * x })
+        .take(6)
+        .collect::<Vec<_>>();
+    assert_eq!(b3, b2);
+}
@@ -0,0 +1,61 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, SystemDatatype, View};
+use mpi::ffi::MPI_Aint;
+use mpi::traits::*;
+use mpi::{Address, Count};
+use std::mem::size_of;
+
+const COLS: Count = 3;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    // Consecutive elements of a buffer of this datatype are taken from consecutive rows of a
+    // row-major matrix with `COLS` columns, i.e. they make up a column of it.
+    let column =
+        SystemDatatype::f64().dup_resized(Address(0), COLS * Address(size_of::<f64>() as MPI_Aint));
+    assert_eq!(column.extent(), COLS * Address(8));
+    assert_eq!(column.size(), 8);
+    assert_eq!(f64::equivalent_datatype().extent(), Address(8));
+
+    // One row per process, each process receives the element of its row in the first column.
+    let mut x = 0.0;
+    let mut matrix = vec![0.0; (size * COLS) as usize];
+    if rank == root_rank {
+        for (i, element) in matrix.iter_mut().enumerate() {
+            *element = i as f64;
+        }
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], size, &column) };
+        root_process.scatter_into_root(&v, &mut x);
+    } else {
+        root_process.scatter_into(&mut x);
+    }
+    assert_eq!(x, f64::from(rank * COLS));
+
+    // Gathering back into the last column leaves the others untouched.
+    let y = -x;
+    if rank == root_rank {
+        {
+            let last = (COLS - 1) as usize;
+            let mut v =
+                unsafe { MutView::with_count_and_datatype(&mut matrix[last..], size, &column) };
+            root_process.gather_into_root(&y, &mut v);
+        }
+        for row in 0..size {
+            let start = (row * COLS) as usize;
+            let expected = f64::from(row * COLS);
+            assert_eq!(matrix[start], expected);
+            assert_eq!(matrix[start + 1], expected + 1.0);
+            assert_eq!(matrix[start + 2], -expected);
+        }
+    } else {
+        root_process.gather_into(&y);
+    }
+}
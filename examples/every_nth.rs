@@ -0,0 +1,43 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::topology::Rank;
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank - 1 + size) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Every 3rd element starting at index 1, i.e. the elements at indices 1, 4, 7 and 10.
+    let t = UserDatatype::every_nth(3, 1, 4, &Rank::equivalent_datatype());
+    assert_eq!(t.size(), 4 * 4);
+    assert_eq!(t.lower_bound(), Address(4));
+
+    let b1 = (0..12).map(|x| rank * 100 + x).collect::<Vec<_>>();
+    let mut b2 = vec![-1; 12];
+    {
+        let v1 = unsafe { View::with_count_and_datatype(&b1[..], 1, &t) };
+        let mut v2 = unsafe { MutView::with_count_and_datatype(&mut b2[..], 1, &t) };
+        p2p::send_receive_into(&v1, &next_process, &mut v2, &previous_process);
+    }
+
+    let expected = (0..12)
+        .map(|x| {
+            if x % 3 == 1 {
+                previous_rank * 100 + x
+            } else {
+                -1
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(expected, b2);
+}
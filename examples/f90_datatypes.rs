@@ -0,0 +1,31 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, SystemDatatype};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // Fortran `DOUBLE PRECISION` is `SELECTED_REAL_KIND(15, 307)` on IEEE 754 platforms.
+    let double_precision = SystemDatatype::f90_real(Some(15), Some(307));
+    assert!(double_precision.is_predefined());
+    assert_eq!(8, double_precision.size());
+
+    let double_complex = SystemDatatype::f90_complex(Some(15), None);
+    assert_eq!(16, double_complex.size());
+
+    let integer = SystemDatatype::f90_integer(9);
+    assert_eq!(4, integer.size());
+
+    let root_process = world.process_at_rank(0);
+
+    let mut value = if world.rank() == 0 { 0.25f64 } else { 0.0 };
+    {
+        let mut view =
+            unsafe { MutView::with_count_and_datatype(&mut value, 1, &double_precision) };
+        root_process.broadcast_into(&mut view);
+    }
+    assert_eq!(0.25, value);
+}
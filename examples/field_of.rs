@@ -0,0 +1,64 @@
+#![deny(warnings)]
+#[macro_use]
+extern crate memoffset;
+#[macro_use]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::ffi::MPI_Aint;
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::{Address, Count};
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct Particle {
+    id: i32,
+    energy: f64,
+    mass: f32,
+}
+
+unsafe impl Equivalence for Particle {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        struct_datatype!(Particle { id, energy, mass })
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_process = world.process_at_rank(0);
+
+    let particles: Vec<Particle> = (0..5)
+        .map(|i| Particle {
+            id: i,
+            energy: f64::from(i) * 1.5,
+            mass: 1.0,
+        })
+        .collect();
+
+    let energy =
+        UserDatatype::field_of::<Particle, f64>(Address(offset_of!(Particle, energy) as MPI_Aint));
+    assert_eq!(energy.size(), 8);
+    assert_eq!(
+        energy.extent(),
+        Address(std::mem::size_of::<Particle>() as MPI_Aint)
+    );
+
+    // Only the energies are gathered from the array of particles, straight into a flat buffer.
+    let mut energies = vec![0.0; particles.len()];
+    if world.rank() == 0 {
+        // The datatype only describes the `f64` energies, which are initialized.
+        let v = unsafe {
+            View::try_with_count_and_datatype(&particles[..], particles.len() as Count, &energy)
+        }
+        .unwrap();
+        let this_process = world.this_process();
+        p2p::send_receive_into(&v, &this_process, &mut energies[..], &this_process);
+    }
+    root_process.broadcast_into(&mut energies[..]);
+
+    let expected: Vec<f64> = particles.iter().map(|p| p.energy).collect();
+    assert_eq!(expected, energies);
+}
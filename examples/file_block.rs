@@ -0,0 +1,44 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype};
+use mpi::ffi::MPI_Aint;
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::{Address, Count};
+use std::mem::size_of;
+
+const LEN: usize = 3;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let this_process = world.process_at_rank(rank);
+
+    // Every process owns a block of `LEN` elements of the global sequence, in order of rank.
+    let global_len = size * LEN as Count;
+    let t = UserDatatype::file_block(
+        global_len,
+        rank * LEN as Count,
+        LEN as Count,
+        &i32::equivalent_datatype(),
+    );
+    assert_eq!(
+        global_len * Address(size_of::<i32>() as MPI_Aint),
+        t.extent()
+    );
+
+    let local = [rank; LEN];
+    let mut global = vec![-1; global_len as usize];
+    {
+        let mut v = unsafe { MutView::with_count_and_datatype(&mut global[..], 1, &t) };
+        p2p::send_receive_into(&local[..], &this_process, &mut v, &this_process);
+    }
+
+    let expected = (0..global_len)
+        .map(|i| if i / LEN as Count == rank { rank } else { -1 })
+        .collect::<Vec<_>>();
+    assert_eq!(expected, global);
+}
@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let mut msg = [0.0f64; 16];
+    for (i, x) in msg.iter_mut().enumerate() {
+        *x = f64::from(rank) * 100.0 + i as f64;
+    }
+    assert_eq!(msg.count(), 16);
+
+    let mut received = [0.0f64; 16];
+    mpi::request::scope(|scope| {
+        let sreq = world.process_at_rank(next_rank).immediate_send(scope, &msg);
+        world
+            .process_at_rank(previous_rank)
+            .receive_into(&mut received);
+        sreq.wait();
+    });
+
+    for (i, &x) in received.iter().enumerate() {
+        assert_eq!(x, f64::from(previous_rank) * 100.0 + i as f64);
+    }
+
+    let mut root_msg = [0.0f64; 16];
+    if rank == 0 {
+        root_msg = msg;
+    }
+    world.process_at_rank(0).broadcast_into(&mut root_msg);
+    assert_eq!(root_msg[15], 15.0);
+}
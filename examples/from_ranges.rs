@@ -0,0 +1,39 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = (rank + size - 1) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Three disjoint segments, deliberately not in order.
+    let t = UserDatatype::from_ranges(&[6..9, 0..2, 4..5], &i32::equivalent_datatype());
+    assert_eq!(t.size(), 6 * 4);
+
+    let buffer: Vec<i32> = (0..10).map(|i| rank * 100 + i).collect();
+    let mut segments = [0; 6];
+    {
+        let v = unsafe { View::with_count_and_datatype(&buffer[..], 1, &t) };
+        p2p::send_receive_into(&v, &next_process, &mut segments[..], &previous_process);
+    }
+
+    let expected: Vec<i32> = [6, 7, 8, 0, 1, 4]
+        .iter()
+        .map(|i| previous_rank * 100 + i)
+        .collect();
+    assert_eq!(&expected[..], &segments[..]);
+
+    // Overlapping ranges can be sent, but not received into.
+    let overlapping = UserDatatype::from_ranges(&[0..3, 2..4], &i32::equivalent_datatype());
+    assert!(overlapping.validate_non_overlapping().is_err());
+}
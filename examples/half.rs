@@ -0,0 +1,31 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use half::{bf16, f16};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_process = world.process_at_rank(0);
+
+    let values = [0.5f32, -1.25, 3.0, 1024.0];
+
+    let mut halves = [f16::from_f32(0.0); 4];
+    let mut brains = [bf16::from_f32(0.0); 4];
+    if world.rank() == 0 {
+        for (i, &value) in values.iter().enumerate() {
+            halves[i] = f16::from_f32(value);
+            brains[i] = bf16::from_f32(value);
+        }
+    }
+
+    root_process.broadcast_into(&mut halves[..]);
+    root_process.broadcast_into(&mut brains[..]);
+
+    for (i, &value) in values.iter().enumerate() {
+        assert_eq!(halves[i].to_f32(), value);
+        assert_eq!(brains[i].to_f32(), value);
+    }
+    assert_eq!(f16::equivalent_datatype().size(), 2);
+}
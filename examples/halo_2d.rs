@@ -0,0 +1,67 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Halo2D, Side};
+use mpi::point_to_point::exchange_halo;
+use mpi::traits::*;
+use mpi::Count;
+
+const ROWS: Count = 6;
+const COLS: Count = 7;
+
+fn value(rank: Count, row: Count, col: Count) -> f64 {
+    f64::from(rank * 1000 + row * 10 + col)
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // The processes are stacked from north to south, east and west wrap around to the same
+    // process.
+    let cart_comm = world
+        .create_cartesian_communicator(&[world.size(), 1], &[false, true], false)
+        .unwrap();
+    let rank = cart_comm.rank();
+    let (north, south) = cart_comm.shift(0, 1);
+    let (west, east) = cart_comm.shift(1, 1);
+
+    let halo = Halo2D::<f64>::new(ROWS, COLS, 1);
+    assert_eq!(halo.datatype(Side::North).size(), (COLS - 2) * 8);
+    assert_eq!(halo.datatype(Side::East).size(), (ROWS - 2) * 8);
+
+    assert_eq!(halo.grid_len(), (ROWS * COLS) as usize);
+
+    let mut grid = vec![-1.0; halo.grid_len()];
+    for row in 1..ROWS - 1 {
+        for col in 1..COLS - 1 {
+            grid[(row * COLS + col) as usize] = value(rank, row, col);
+        }
+    }
+
+    exchange_halo(&cart_comm, &halo, &mut grid, [north, south, east, west]);
+
+    let at = |row: Count, col: Count| grid[(row * COLS + col) as usize];
+
+    for col in 1..COLS - 1 {
+        let expected = north.map_or(-1.0, |north| value(north, ROWS - 2, col));
+        assert_eq!(at(0, col), expected);
+        let expected = south.map_or(-1.0, |south| value(south, 1, col));
+        assert_eq!(at(ROWS - 1, col), expected);
+    }
+
+    for row in 1..ROWS - 1 {
+        assert_eq!(at(row, 0), value(rank, row, COLS - 2));
+        assert_eq!(at(row, COLS - 1), value(rank, row, 1));
+    }
+
+    for &(row, col) in &[(0, 0), (0, COLS - 1), (ROWS - 1, 0), (ROWS - 1, COLS - 1)] {
+        assert_eq!(at(row, col), -1.0);
+    }
+
+    for row in 1..ROWS - 1 {
+        for col in 1..COLS - 1 {
+            assert_eq!(at(row, col), value(rank, row, col));
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #![deny(warnings)]
 extern crate mpi;
 
-use mpi::datatype::PartitionMut;
+use mpi::datatype::{displacements_from_counts, PartitionMut};
 use mpi::traits::*;
 use mpi::Count;
 
@@ -15,14 +15,7 @@ fn main() {
     let msg: Vec<_> = (0..rank).collect();
 
     let counts: Vec<Count> = (0..size).collect();
-    let displs: Vec<Count> = counts
-        .iter()
-        .scan(0, |acc, &x| {
-            let tmp = *acc;
-            *acc += x;
-            Some(tmp)
-        })
-        .collect();
+    let displs = displacements_from_counts(&counts);
 
     let mut buf = vec![0; (size * (size - 1) / 2) as usize];
     {
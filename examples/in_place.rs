@@ -0,0 +1,44 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let mut sums = [rank, 2 * rank];
+    world.all_reduce_in_place(&mut sums[..], SystemOperation::sum());
+    let total = size * (size - 1) / 2;
+    assert_eq!([total, 2 * total], sums);
+
+    // Every rank fills in its own block.
+    let mut ranks = vec![-1 as Rank; size as usize];
+    ranks[rank as usize] = rank;
+    world.all_gather_in_place(&mut ranks[..]);
+    assert_eq!((0..size).collect::<Vec<_>>(), ranks);
+
+    let mut max = rank;
+    if rank == root_rank {
+        root_process.reduce_into_root_in_place(&mut max, SystemOperation::max());
+        assert_eq!(size - 1, max);
+    } else {
+        root_process.reduce_into(&max, SystemOperation::max());
+    }
+
+    let square = rank * rank;
+    if rank == root_rank {
+        let mut squares = vec![-1 as Rank; size as usize];
+        squares[rank as usize] = square;
+        root_process.gather_into_root_in_place(&mut squares[..]);
+        assert_eq!((0..size).map(|r| r * r).collect::<Vec<_>>(), squares);
+    } else {
+        root_process.gather_into(&square);
+    }
+}
@@ -0,0 +1,78 @@
+#![deny(warnings)]
+#[macro_use]
+extern crate mpi;
+
+use mpi::datatype::{Interleaved, UserDatatype};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+struct Record {
+    id: i32,
+    energy: f64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+struct Sample {
+    count: u64,
+    mean: f64,
+}
+
+unsafe impl Equivalence for Sample {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        struct_datatype!(Sample { count, mean })
+    }
+}
+
+unsafe impl Equivalence for Record {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        struct_datatype!(Record { id, energy })
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let this_process = world.this_process();
+
+    // The fields are kept in separate arrays, a struct of arrays.
+    let ids: Vec<i32> = (0..4).map(|i| rank * 10 + i).collect();
+    let energies: Vec<f64> = ids.iter().map(|&id| f64::from(id) * 0.5).collect();
+
+    let interleaved = Interleaved::builder()
+        .field(&ids[..])
+        .field(&energies[..])
+        .build();
+
+    // They arrive as an array of structs.
+    let mut records = vec![Record::default(); ids.len()];
+    p2p::send_receive_into(&interleaved, &this_process, &mut records[..], &this_process);
+
+    let expected: Vec<Record> = ids
+        .iter()
+        .zip(&energies)
+        .map(|(&id, &energy)| Record { id, energy })
+        .collect();
+    assert_eq!(expected, records);
+
+    // Elements of the same size are described by a single record that is repeated.
+    let counts: Vec<u64> = (0..4).map(|i| i as u64 + 1).collect();
+    let means: Vec<f64> = counts.iter().map(|&count| count as f64 * 0.25).collect();
+    let interleaved = Interleaved::builder()
+        .field(&counts[..])
+        .field(&means[..])
+        .build();
+    assert_eq!(counts.len() as mpi::Count, interleaved.count());
+
+    let mut samples = vec![Sample::default(); counts.len()];
+    p2p::send_receive_into(&interleaved, &this_process, &mut samples[..], &this_process);
+    for (i, sample) in samples.iter().enumerate() {
+        assert_eq!(counts[i], sample.count);
+        assert_eq!(means[i], sample.mean);
+    }
+}
@@ -0,0 +1,57 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Field, MutView, SystemDatatype, UserDatatype};
+use mpi::traits::*;
+use mpi::Address;
+
+const RECORD_SIZE: usize = 32;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    // A record of one `i32` at byte 0 and two `f64` at byte 8, as e.g. read from a config file.
+    let layout = [
+        Field {
+            base: SystemDatatype::i32(),
+            offset: Address(0),
+            count: 1,
+        },
+        Field {
+            base: SystemDatatype::f64(),
+            offset: Address(8),
+            count: 2,
+        },
+    ];
+    let t = UserDatatype::from_layout(&layout);
+    assert_eq!(Address(24), t.extent());
+
+    // The same layout built from an iterator
+    let fields = layout
+        .iter()
+        .map(|field| (field.base, field.count, field.offset));
+    let u = UserDatatype::from_fields(fields);
+    assert_eq!(t.to_descriptor(), u.to_descriptor());
+    let doubles = (0..3).map(|i| (SystemDatatype::f64(), 1, Address(8 * i)));
+    assert_eq!(Address(24), UserDatatype::from_fields(doubles).extent());
+
+    let mut expected = [0u8; RECORD_SIZE];
+    expected[0..4].copy_from_slice(&7i32.to_ne_bytes());
+    expected[8..16].copy_from_slice(&1.5f64.to_ne_bytes());
+    expected[16..24].copy_from_slice(&(-2.5f64).to_ne_bytes());
+
+    let mut record = if rank == 0 {
+        expected
+    } else {
+        [0u8; RECORD_SIZE]
+    };
+
+    {
+        let mut v = unsafe { MutView::with_count_and_datatype(&mut record[..], 1, &t) };
+        world.process_at_rank(0).broadcast_into(&mut v);
+    }
+
+    assert_eq!(expected, record);
+}
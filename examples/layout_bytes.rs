@@ -0,0 +1,56 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::alloc::{self, Layout};
+use std::mem::size_of;
+use std::{ptr, slice};
+
+use mpi::datatype::{MutView, UserDatatype};
+use mpi::ffi::MPI_Aint;
+use mpi::traits::*;
+use mpi::{Address, Count};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Particle {
+    position: [f64; 3],
+    charge: f32,
+    id: u16,
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let layout = Layout::new::<Particle>();
+    let opaque = UserDatatype::from_layout_bytes(layout);
+    assert_eq!(opaque.size(), size_of::<Particle>() as Count);
+    assert_eq!(opaque.extent(), Address(size_of::<Particle>() as MPI_Aint));
+    assert!(!opaque.is_portable());
+
+    let empty = UserDatatype::from_layout_bytes(Layout::new::<()>());
+    assert_eq!(empty.size(), 0);
+
+    let expected = Particle {
+        position: [1.0, 2.0, 3.0],
+        charge: -1.0,
+        id: 42,
+    };
+
+    // An allocation the datatype knows nothing about but its layout.
+    let block = unsafe { alloc::alloc_zeroed(layout) };
+    assert!(!block.is_null());
+    if world.rank() == 0 {
+        unsafe { ptr::write(block as *mut Particle, expected) };
+    }
+
+    {
+        let bytes = unsafe { slice::from_raw_parts_mut(block, layout.size()) };
+        let mut v = unsafe { MutView::with_count_and_datatype(bytes, 1, &opaque) };
+        world.process_at_rank(0).broadcast_into(&mut v);
+    }
+
+    let particle = unsafe { ptr::read(block as *const Particle) };
+    assert_eq!(particle, expected);
+    unsafe { alloc::dealloc(block, layout) };
+}
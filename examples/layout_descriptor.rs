@@ -0,0 +1,76 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Combiner, DescriptorError, LayoutDescriptor, MutView, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let pair = UserDatatype::contiguous(2, &f64::equivalent_datatype());
+    let every_other = UserDatatype::vector(2, 1, 2, &pair);
+    let padded = UserDatatype::resized(&i32::equivalent_datatype(), Address(0), Address(8));
+    let record =
+        UserDatatype::structured(&[1, 2], &[Address(0), Address(48)], &[every_other, padded]);
+
+    let descriptor = record.to_descriptor();
+    match descriptor {
+        LayoutDescriptor::Derived {
+            combiner,
+            ref integers,
+            ref addresses,
+            ref datatypes,
+        } => {
+            assert_eq!(combiner, Combiner::Structured);
+            assert_eq!(integers, &[2, 1, 2]);
+            assert_eq!(addresses, &[Address(0), Address(48)]);
+            assert_eq!(datatypes.len(), 2);
+        }
+        LayoutDescriptor::Predefined(_) => panic!("record is not a predefined datatype"),
+    }
+
+    let rebuilt = UserDatatype::from_descriptor(&descriptor).unwrap();
+    assert_eq!(rebuilt.to_descriptor(), descriptor);
+    assert_eq!(rebuilt.size(), record.size());
+    assert_eq!(rebuilt.extent(), record.extent());
+
+    let f64_descriptor = f64::equivalent_datatype().to_descriptor();
+    let f64_rebuilt = UserDatatype::from_descriptor(&f64_descriptor).unwrap();
+    assert_eq!(f64_rebuilt.size(), 8);
+
+    let unknown = LayoutDescriptor::Predefined("MPI_UNKNOWN".to_string());
+    assert_eq!(
+        UserDatatype::from_descriptor(&unknown).unwrap_err(),
+        DescriptorError::UnknownPredefined("MPI_UNKNOWN".to_string())
+    );
+    let malformed = LayoutDescriptor::Derived {
+        combiner: Combiner::Vector,
+        integers: vec![2, 1],
+        addresses: vec![],
+        datatypes: vec![f64_descriptor],
+    };
+    assert_eq!(
+        UserDatatype::from_descriptor(&malformed).unwrap_err(),
+        DescriptorError::Malformed(Combiner::Vector)
+    );
+
+    // The original and the rebuilt datatype describe the same layout.
+    let mut buffer = [0u8; 64];
+    for (i, x) in buffer.iter_mut().enumerate() {
+        *x = i as u8;
+    }
+    let mut received = [0u8; 64];
+    {
+        let v = unsafe { View::with_count_and_datatype(&buffer[..], 1, &record) };
+        let mut r = unsafe { MutView::with_count_and_datatype(&mut received[..], 1, &rebuilt) };
+        p2p::send_receive_into(&v, &this_process, &mut r, &this_process);
+    }
+    for (i, &x) in received.iter().enumerate() {
+        let selected = i < 16 || (32..52).contains(&i) || (56..60).contains(&i);
+        assert_eq!(x, if selected { i as u8 } else { 0 });
+    }
+}
@@ -0,0 +1,51 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{LazyDatatype, LazyMutView, LazyView, UncommittedUserDatatype};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    // A pair of elements, only ever used as a building block.
+    let pair = LazyDatatype::new(UncommittedUserDatatype::contiguous(
+        2,
+        &i32::equivalent_datatype(),
+    ));
+    // Every other pair, which is actually communicated.
+    let root = LazyDatatype::new(UncommittedUserDatatype::vector(2, 1, 2, &pair));
+    assert!(!pair.is_committed());
+    assert!(!root.is_committed());
+
+    // Nothing is committed until the views are passed to communication.
+    let buffer = [1, 2, -1, -1, 3, 4];
+    let mut received = [0; 4];
+    {
+        let v = unsafe { LazyView::with_count_and_datatype(&buffer[..], 1, &root) };
+        assert!(!root.is_committed());
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!([1, 2, 3, 4], received);
+
+    assert!(root.is_committed());
+    assert!(!pair.is_committed());
+
+    // Receiving into every other pair commits it on the receiving side as well.
+    let strided = LazyDatatype::new(UncommittedUserDatatype::vector(2, 1, 2, &pair));
+    let mut scattered = [0; 6];
+    {
+        let mut v =
+            unsafe { LazyMutView::with_count_and_datatype(&mut scattered[..], 1, &strided) };
+        assert!(!strided.is_committed());
+        p2p::send_receive_into(&received[..], &this_process, &mut v, &this_process);
+    }
+    assert_eq!([1, 2, 0, 0, 3, 4], scattered);
+    assert!(strided.is_committed());
+    assert!(!pair.is_committed());
+
+    // The committed datatype is cached.
+    assert_eq!(root.committed().as_raw(), root.committed().as_raw());
+}
@@ -0,0 +1,59 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Order, SystemDatatype, UserDatatype};
+use mpi::raw::AsRaw;
+use mpi::traits::*;
+use mpi::Address;
+
+fn raw(types: Option<Vec<(SystemDatatype, usize)>>) -> Vec<(mpi::ffi::MPI_Datatype, usize)> {
+    types
+        .expect("datatype can be decoded")
+        .iter()
+        .map(|&(datatype, len)| (datatype.as_raw(), len))
+        .collect()
+}
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let int = i32::equivalent_datatype();
+    let double = f64::equivalent_datatype();
+    let byte = u8::equivalent_datatype();
+
+    assert_eq!(raw(double.leaf_types()), [(double.as_raw(), 1)]);
+
+    // The fields of a struct in order, with their block lengths
+    let record = UserDatatype::structured(
+        &[2, 1, 3],
+        &[Address(0), Address(8), Address(16)],
+        &[int, double, byte],
+    );
+    assert_eq!(
+        raw(record.leaf_types()),
+        [(int.as_raw(), 2), (double.as_raw(), 1), (byte.as_raw(), 3)]
+    );
+
+    // Repetitions of the record repeat its base types.
+    let array = UserDatatype::subarray(&[4, 4], &[2, 2], &[1, 1], Order::C, &record);
+    let runs = raw(array.leaf_types());
+    assert_eq!(runs.len(), 3 * 4);
+    assert_eq!(runs[3], (int.as_raw(), 2));
+
+    // Consecutive fields of the same type form a single run that counts all of them.
+    let doubles = UserDatatype::structured(&[1, 2], &[Address(0), Address(8)], &[double, double]);
+    assert_eq!(raw(doubles.leaf_types()), [(double.as_raw(), 3)]);
+    let total: usize = raw(doubles.leaf_types()).iter().map(|&(_, len)| len).sum();
+    assert_eq!(total as mpi::Count * 8, doubles.size());
+
+    // Empty blocks do not contribute anything.
+    let sparse = UserDatatype::structured(
+        &[1, 0, 1],
+        &[Address(0), Address(4), Address(8)],
+        &[int, byte, double],
+    );
+    assert_eq!(
+        raw(sparse.leaf_types()),
+        [(int.as_raw(), 1), (double.as_raw(), 1)]
+    );
+}
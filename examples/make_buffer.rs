@@ -0,0 +1,29 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    if rank == 0 {
+        for destination in 1..size {
+            let msg: Vec<f64> = (0..destination).map(f64::from).collect();
+            world.process_at_rank(destination).send(&msg[..]);
+        }
+    } else {
+        let source = world.process_at_rank(0);
+        let status = source.probe();
+
+        let mut buf = status.make_buffer::<f64>();
+        assert_eq!(rank as usize, buf.len());
+        assert!(buf.iter().all(|&x| x == 0.0));
+
+        source.receive_into(&mut buf[..]);
+        let expected: Vec<f64> = (0..rank).map(f64::from).collect();
+        assert_eq!(expected, buf);
+    }
+}
@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Order, UserDatatype};
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let int = i32::equivalent_datatype();
+
+    // Three blocks of two `i32` each, four `i32` apart
+    let strided = UserDatatype::vector(3, 2, 4, &int);
+    assert_eq!(strided.memory_map(1), vec![0..8, 16..24, 32..40]);
+    // The second instance starts right after the last block of the first one.
+    assert_eq!(
+        strided.memory_map(2),
+        vec![0..8, 16..24, 32..48, 56..64, 72..80]
+    );
+    assert!(strided.memory_map(0).is_empty());
+
+    // Overlapping blocks show up as intersecting ranges.
+    let overlapping = UserDatatype::vector(2, 2, 1, &int);
+    assert_eq!(overlapping.memory_map(1), vec![0..8, 4..12]);
+
+    // Holes between the fields of a struct
+    let record = UserDatatype::structured(
+        &[1, 1],
+        &[Address(0), Address(8)],
+        &[int, f64::equivalent_datatype()],
+    );
+    assert_eq!(record.memory_map(1), vec![0..4, 8..16]);
+
+    // Elements (1, 1), (2, 1), (1, 2) and (2, 2) of a 4 x 3 array in column-major order
+    let block = UserDatatype::subarray(&[4, 3], &[2, 2], &[1, 1], Order::Fortran, &int);
+    assert_eq!(block.memory_map(1), vec![20..28, 36..44]);
+    let block = UserDatatype::subarray(&[4, 3], &[2, 2], &[1, 1], Order::C, &int);
+    assert_eq!(block.memory_map(1), vec![16..24, 28..36]);
+}
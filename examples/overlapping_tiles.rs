@@ -0,0 +1,45 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_process = world.process_at_rank((rank - 1 + size) % size);
+
+    let signal = (0..10).map(|x| x + 100 * rank).collect::<Vec<i32>>();
+    let offset = 100 * ((rank - 1 + size) % size);
+
+    // Tiles of four elements sharing one element with their neighbours
+    let t = UserDatatype::overlapping_tiles(10, 4, 1, &i32::equivalent_datatype());
+    assert_eq!(t.size(), 12 * 4);
+
+    let mut tiles = [0; 12];
+    {
+        let v = unsafe { View::with_count_and_datatype(&signal[..], 1, &t) };
+        p2p::send_receive_into(&v, &next_process, &mut tiles[..], &previous_process);
+    }
+    let expected = [0, 1, 2, 3, 3, 4, 5, 6, 6, 7, 8, 9];
+    for (tile, expected) in tiles.iter().zip(&expected) {
+        assert_eq!(*tile, expected + offset);
+    }
+
+    // The last tile is shortened to end with the sequence.
+    let t = UserDatatype::overlapping_tiles(9, 4, 2, &i32::equivalent_datatype());
+    assert_eq!(t.size(), 15 * 4);
+    let mut tiles = [0; 15];
+    {
+        let v = unsafe { View::with_count_and_datatype(&signal[..], 1, &t) };
+        p2p::send_receive_into(&v, &next_process, &mut tiles[..], &previous_process);
+    }
+    let expected = [0, 1, 2, 3, 2, 3, 4, 5, 4, 5, 6, 7, 6, 7, 8];
+    for (tile, expected) in tiles.iter().zip(&expected) {
+        assert_eq!(*tile, expected + offset);
+    }
+}
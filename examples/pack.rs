@@ -10,10 +10,17 @@ fn main() {
     let ints = [3i32, 2, 1];
     let packed = world.pack(&ints[..]);
 
+    let packed_size = ints[..].packed_size(&world);
+    assert_eq!(world.pack_size(3, &i32::equivalent_datatype()), packed_size);
+    assert!(packed.len() <= packed_size as usize);
+
     let mut new_ints = [0, 0, 0];
     unsafe {
         world.unpack_into(&packed, &mut new_ints[..], 0);
     }
 
     assert_eq!([3, 2, 1], new_ints);
+
+    let unpacked = unsafe { world.unpack_iter::<i32>(&packed) }.collect::<Vec<_>>();
+    assert_eq!(vec![3, 2, 1], unpacked);
 }
@@ -0,0 +1,31 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{datatype_pack_roundtrip, UserDatatype};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // Neither `[f64; 6]` nor `i32` contain any padding.
+    let values = [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let double = f64::equivalent_datatype();
+
+    // Three blocks of two elements two apart cover all six elements.
+    let complete = UserDatatype::vector(3, 2, 2, &double);
+    assert!(unsafe { datatype_pack_roundtrip(&values, &complete, &world) });
+
+    // Taking every other element skips half of the array.
+    let every_other = UserDatatype::vector(3, 1, 2, &double);
+    assert!(!unsafe { datatype_pack_roundtrip(&values, &every_other, &world) });
+
+    // A datatype reaching beyond the array is rejected without accessing it.
+    let too_long = UserDatatype::vector(4, 2, 2, &double);
+    assert!(!unsafe { datatype_pack_roundtrip(&values, &too_long, &world) });
+
+    let contiguous = UserDatatype::contiguous(6, &double);
+    assert!(unsafe { datatype_pack_roundtrip(&values[..], &contiguous, &world) });
+    let int = i32::equivalent_datatype();
+    assert!(unsafe { datatype_pack_roundtrip(&7i32, &int, &world) });
+}
@@ -0,0 +1,80 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, Order, Triangle, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Count;
+
+const N: usize = 4;
+const PACKED_LEN: usize = N * (N + 1) / 2;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    // Element (i, j) of a column-major N x N matrix is stored at index i + N * j.
+    let value = |i: usize, j: usize| (10 * i + j) as f64;
+    let mut full = [0.0; N * N];
+    for j in 0..N {
+        for i in 0..N {
+            full[i + N * j] = value(i, j);
+        }
+    }
+
+    for &kind in &[Triangle::Lower, Triangle::Upper] {
+        // LAPACK packed storage, one column of the triangle after the other
+        let mut expected = Vec::with_capacity(PACKED_LEN);
+        for j in 0..N {
+            let rows = match kind {
+                Triangle::Lower => j..N,
+                Triangle::Upper => 0..j + 1,
+            };
+            expected.extend(rows.map(|i| value(i, j)));
+        }
+
+        let t = UserDatatype::packed_triangular(
+            N as Count,
+            kind,
+            Order::Fortran,
+            &f64::equivalent_datatype(),
+        );
+
+        // Full to packed
+        let mut packed = [0.0; PACKED_LEN];
+        {
+            let v = unsafe { View::with_count_and_datatype(&full[..], 1, &t) };
+            p2p::send_receive_into(&v, &this_process, &mut packed[..], &this_process);
+        }
+        assert_eq!(&packed[..], &expected[..]);
+
+        // Packed back to full, leaving the other triangle untouched
+        let mut unpacked = [-1.0; N * N];
+        {
+            let mut v = unsafe { MutView::with_count_and_datatype(&mut unpacked[..], 1, &t) };
+            p2p::send_receive_into(&packed[..], &this_process, &mut v, &this_process);
+        }
+        for j in 0..N {
+            for i in 0..N {
+                let in_triangle = match kind {
+                    Triangle::Lower => i >= j,
+                    Triangle::Upper => i <= j,
+                };
+                let expected = if in_triangle { value(i, j) } else { -1.0 };
+                assert_eq!(unpacked[i + N * j], expected);
+            }
+        }
+    }
+
+    // In row-major order the triangle is packed one row after the other.
+    let t =
+        UserDatatype::packed_triangular(3, Triangle::Lower, Order::C, &i32::equivalent_datatype());
+    let matrix = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let mut packed = [0; 6];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut packed[..], &this_process);
+    }
+    assert_eq!(packed, [1, 4, 5, 7, 8, 9]);
+}
@@ -0,0 +1,42 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = if rank > 0 { rank - 1 } else { size - 1 };
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Two rows of three interior cells each, padded by a ghost cell on either side
+    let grid = [
+        -1,
+        rank,
+        rank + 1,
+        rank + 2,
+        -1,
+        -1,
+        rank + 3,
+        rank + 4,
+        rank + 5,
+        -1,
+    ];
+
+    let t = UserDatatype::padded(3, 5, &i32::equivalent_datatype());
+    let mut interior = [0; 6];
+    {
+        let v = unsafe { View::with_count_and_datatype(&grid[1..], 2, &t) };
+        p2p::send_receive_into(&v, &next_process, &mut interior[..], &previous_process);
+    }
+
+    let p = previous_rank;
+    assert_eq!([p, p + 1, p + 2, p + 3, p + 4, p + 5], interior);
+}
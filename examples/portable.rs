@@ -0,0 +1,37 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Order, UserDatatype};
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let int = i32::equivalent_datatype();
+    let double = f64::equivalent_datatype();
+
+    assert!(double.is_portable());
+    assert!(u8::equivalent_datatype().is_portable());
+
+    let record = UserDatatype::structured(&[2, 1], &[Address(0), Address(8)], &[int, double]);
+    assert!(record.is_portable());
+    let array = UserDatatype::subarray(&[4, 4], &[2, 2], &[1, 1], Order::C, &record);
+    assert!(array.is_portable());
+
+    // MPI does not convert raw bytes.
+    let bytes = 1.0f64.as_byte_buffer();
+    assert!(!bytes.as_datatype().is_portable());
+    let raw = UserDatatype::contiguous(8, &bytes.as_datatype());
+    assert!(!raw.is_portable());
+
+    // A single untyped field makes the whole record non-portable.
+    let mixed = UserDatatype::structured(
+        &[1, 4],
+        &[Address(0), Address(4)],
+        &[int, bytes.as_datatype()],
+    );
+    assert!(!mixed.is_portable());
+    let nested = UserDatatype::vector(3, 1, 2, &mixed);
+    assert!(!nested.is_portable());
+}
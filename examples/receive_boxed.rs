@@ -0,0 +1,42 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::request::WaitGuard;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next_rank = (rank + 1) % size;
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = (rank - 1 + size) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Every process sends as many elements as its rank, so rank 0 sends an empty message.
+    let msg = (0..rank).map(|x| f64::from(x) * 0.5).collect::<Vec<_>>();
+    mpi::request::scope(|scope| {
+        let _sreq = WaitGuard::from(next_process.immediate_send_with_tag(scope, &msg[..], 7));
+
+        let (received, status) = previous_process.receive_boxed_with_tag::<f64>(7);
+        assert_eq!(status.source_rank(), previous_rank);
+        assert_eq!(status.tag(), 7);
+        assert_eq!(received.len(), previous_rank as usize);
+        for (i, &x) in received.iter().enumerate() {
+            assert_eq!(x, i as f64 * 0.5);
+        }
+    });
+
+    world.barrier();
+
+    if rank == 0 {
+        for destination in 1..size {
+            world.process_at_rank(destination).send(&[1u8; 3][..]);
+        }
+    } else {
+        let (received, _) = world.process_at_rank(0).receive_boxed::<u8>();
+        assert_eq!(&received[..], &[1, 1, 1]);
+    }
+}
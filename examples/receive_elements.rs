@@ -0,0 +1,32 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let triple = UserDatatype::contiguous(3, &i32::equivalent_datatype());
+    let msg = [1, 2, 3, 4, 5];
+    let mut buf = [0; 9];
+
+    mpi::request::scope(|scope| {
+        let sreq = this_process.immediate_send(scope, &msg);
+        let (elements, status) = {
+            let mut view = unsafe { MutView::with_count_and_datatype(&mut buf[..], 3, &triple) };
+            this_process.receive_elements_into(&mut view)
+        };
+        sreq.wait();
+
+        // Five integers arrived, which is not a whole number of triples.
+        assert_eq!(elements, 5);
+        assert_eq!(status.elements(&triple), 5);
+        assert_eq!(status.elements(i32::equivalent_datatype()), 5);
+        assert_eq!(status.count(i32::equivalent_datatype()), 5);
+    });
+
+    assert_eq!(buf, [1, 2, 3, 4, 5, 0, 0, 0, 0]);
+}
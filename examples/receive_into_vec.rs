@@ -0,0 +1,32 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    if rank == 0 {
+        for destination in 1..size {
+            for len in &[3, 10, 0, 5] {
+                let msg: Vec<u32> = (0..len * destination as u32).collect();
+                world.process_at_rank(destination).send(&msg[..]);
+            }
+        }
+    } else {
+        // The same buffer is reused for messages of varying length.
+        let mut buf = vec![42u32; 7];
+        for len in &[3, 10, 0, 5] {
+            let status = world.process_at_rank(0).receive_into_vec(&mut buf);
+            let expected: Vec<u32> = (0..len * rank as u32).collect();
+            assert_eq!(
+                expected.len() as i32,
+                status.count(u32::equivalent_datatype())
+            );
+            assert_eq!(expected, buf);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+use std::mem::MaybeUninit;
+
+const CAPACITY: usize = 1024;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    if rank == 0 {
+        for destination in 1..size {
+            let msg: Vec<i64> = (0..i64::from(destination) * 10).collect();
+            world.process_at_rank(destination).send(&msg[..]);
+        }
+    } else {
+        let mut buf = vec![MaybeUninit::<i64>::uninit(); CAPACITY];
+        let status = world.process_at_rank(0).receive_into(&mut buf[..]);
+
+        let count = status.count(i64::equivalent_datatype()) as usize;
+        assert_eq!(rank as usize * 10, count);
+
+        // Only the first `count` elements have been written by the receive.
+        let received: Vec<i64> = buf[..count]
+            .iter()
+            .map(|x| unsafe { x.assume_init() })
+            .collect();
+        assert_eq!((0..i64::from(rank) * 10).collect::<Vec<_>>(), received);
+    }
+}
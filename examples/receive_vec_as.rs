@@ -0,0 +1,30 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank - 1 + size) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Single precision on the wire, double precision locally
+    let msg = [rank as f32 + 0.5, rank as f32 + 0.25];
+    mpi::request::scope(|scope| {
+        let sreq = next_process.immediate_send(scope, &msg[..]);
+        let (values, _) = previous_process.receive_vec_as::<f32, f64>();
+        assert_eq!(
+            vec![
+                f64::from(previous_rank) + 0.5,
+                f64::from(previous_rank) + 0.25
+            ],
+            values
+        );
+        sreq.wait();
+    });
+}
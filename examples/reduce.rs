@@ -90,6 +90,11 @@ fn main() {
     world.all_reduce_into(&rank, &mut max, SystemOperation::max());
     assert_eq!(max, size - 1);
 
+    // Single values are buffers of count 1, no slice required.
+    let mut total = 0.0f64;
+    world.all_reduce_into(&0.5f64, &mut total, SystemOperation::sum());
+    assert_eq!(total, 0.5 * f64::from(size));
+
     let a: u16 = 0b0000_1111_1111_0000;
     let b: u16 = 0b0011_1100_0011_1100;
 
@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::ffi::MPI_Aint;
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::size_of;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let int_size = Address(size_of::<i32>() as MPI_Aint);
+
+    let t = UserDatatype::contiguous(1, &i32::equivalent_datatype());
+    assert_eq!(Address(0), t.lower_bound());
+    assert_eq!(int_size, t.extent());
+
+    // Consecutive elements of the resized type are two `i32` apart.
+    let strided = t.with_extent(Address(0), 2 * int_size);
+    assert_eq!(Address(0), strided.lower_bound());
+    assert_eq!(2 * int_size, strided.extent());
+
+    // Allocations follow the extent, while the size only counts the data.
+    assert_eq!(int_size, Address::from(strided.size()));
+    assert_eq!(6 * int_size, strided.bytes_for(3));
+    assert_eq!(3, strided.elements_in(6 * int_size));
+    assert_eq!(3, strided.elements_in(7 * int_size));
+    assert_eq!(6, t.elements_in(6 * int_size));
+
+    // The original datatype is unaffected.
+    assert_eq!(int_size, t.extent());
+
+    let shifted = UserDatatype::resized(&t, -int_size, 3 * int_size);
+    assert_eq!(-int_size, shifted.lower_bound());
+    assert_eq!(3 * int_size, shifted.extent());
+
+    let root_process = world.process_at_rank(0);
+
+    if rank == 0 {
+        let data = [1, -1, 2, -2, 3, -3];
+        let v = unsafe { View::with_count_and_datatype(&data[..], 3, &strided) };
+        assert_eq!(6 * int_size, v.byte_extent());
+        assert_eq!(3 * int_size, data[..3].byte_extent());
+        for destination in 1..world.size() {
+            world.process_at_rank(destination).send(&v);
+        }
+    } else {
+        let mut data = [0i32; 3];
+        root_process.receive_into(&mut data[..]);
+        assert_eq!([1, 2, 3], data);
+    }
+}
@@ -0,0 +1,41 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::collective::SystemOperation;
+use mpi::datatype::{SystemDatatype, UserDatatype, View};
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let mut x = 0.0;
+    {
+        // The predefined `MPI_DOUBLE` with an extent of two `f64`, so that consecutive elements
+        // of a buffer are taken from every other `f64`
+        let every_other = UserDatatype::resized(&SystemDatatype::f64(), Address(0), Address(16));
+        assert_eq!(every_other.extent(), Address(16));
+        assert_eq!(every_other.size(), 8);
+
+        if rank == root_rank {
+            let values = (0..2 * size).map(f64::from).collect::<Vec<_>>();
+            let v = unsafe { View::with_count_and_datatype(&values[..], size, &every_other) };
+            root_process.scatter_into_root(&v, &mut x);
+        } else {
+            root_process.scatter_into(&mut x);
+        }
+    }
+    assert_eq!(x, f64::from(2 * rank));
+
+    // Freeing the resized datatype leaves the predefined one untouched.
+    let double = f64::equivalent_datatype();
+    assert_eq!(double.extent(), Address(8));
+    let mut sum = 0.0;
+    world.all_reduce_into(&x, &mut sum, SystemOperation::sum());
+    assert_eq!(sum, f64::from(size * (size - 1)));
+}
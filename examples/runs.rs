@@ -0,0 +1,52 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::UserDatatype;
+use mpi::ffi::MPI_Aint;
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::size_of;
+
+#[repr(C)]
+#[derive(Default, Debug, PartialEq)]
+struct Sample {
+    position: [f64; 3],
+    cell: [i32; 2],
+}
+
+unsafe impl Equivalence for Sample {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        UserDatatype::runs(&[
+            (f64::equivalent_datatype(), 3),
+            (i32::equivalent_datatype(), 2),
+        ])
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_process = world.process_at_rank(0);
+
+    let t = Sample::equivalent_datatype();
+    assert_eq!(Address(size_of::<Sample>() as MPI_Aint), t.extent());
+
+    let mut sample = if world.rank() == 0 {
+        Sample {
+            position: [1.0, 2.0, 3.0],
+            cell: [4, 5],
+        }
+    } else {
+        Sample::default()
+    };
+    root_process.broadcast_into(&mut sample);
+
+    assert_eq!(
+        Sample {
+            position: [1.0, 2.0, 3.0],
+            cell: [4, 5],
+        },
+        sample
+    );
+}
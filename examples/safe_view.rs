@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{OutOfBoundsError, SafeView, TypedDatatype};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use std::mem::size_of;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.process_at_rank(world.rank());
+
+    // Every other element, starting with the first
+    let every_other = TypedDatatype::<f64>::vector(4, 1, 2);
+    assert_eq!(every_other.size(), 4 * size_of::<f64>() as mpi::Count);
+
+    let values: Vec<f64> = (0..8).map(f64::from).collect();
+    let v = SafeView::new(&values[..], 1, &every_other).unwrap();
+    let mut received = [0.0; 4];
+    p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    assert_eq!([0.0, 2.0, 4.0, 6.0], received);
+
+    // The last block would be the seventh element, but there are only six.
+    assert_eq!(
+        Err(OutOfBoundsError {
+            count: 1,
+            size: 6 * size_of::<f64>()
+        }),
+        SafeView::new(&values[..6], 1, &every_other).map(|_| ())
+    );
+
+    // Two consecutive pairs, the datatype can also be used like any other.
+    let pairs = TypedDatatype::<f64>::contiguous(2);
+    let v = SafeView::new(&values[2..], 2, &pairs).unwrap();
+    let mut received = [0.0; 4];
+    p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    assert_eq!([2.0, 3.0, 4.0, 5.0], received);
+    assert_eq!(pairs.datatype().size(), pairs.size());
+}
@@ -1,7 +1,7 @@
 #![deny(warnings)]
 extern crate mpi;
 
-use mpi::datatype::Partition;
+use mpi::datatype::{displacements_from_counts, Partition};
 use mpi::traits::*;
 use mpi::Count;
 
@@ -20,14 +20,7 @@ fn main() {
     if rank == root_rank {
         let msg: Vec<_> = (0..size).flat_map(|i| (0..i)).collect();
         let counts: Vec<Count> = (0..size).collect();
-        let displs: Vec<Count> = counts
-            .iter()
-            .scan(0, |acc, &x| {
-                let tmp = *acc;
-                *acc += x;
-                Some(tmp)
-            })
-            .collect();
+        let displs = displacements_from_counts(&counts);
         let partition = Partition::new(&msg[..], counts, &displs[..]);
         root_process.scatter_varcount_into_root(&partition, &mut buf[..]);
     } else {
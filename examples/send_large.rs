@@ -0,0 +1,51 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let msg = (0..10).collect::<Vec<u64>>();
+
+    if rank == 0 {
+        let destination = world.process_at_rank(1);
+
+        // Ten elements in chunks of three are sent as four messages.
+        destination.send_chunked_with_tag(&msg[..], 3, 7);
+        destination.send_chunked_with_tag(&msg[..], 3, 8);
+        destination.send_chunked_with_tag(&msg[..0], 3, 9);
+        destination.send_large(&msg[..]);
+    } else if rank == 1 {
+        let source = world.process_at_rank(0);
+
+        let mut lengths = Vec::new();
+        let mut received = Vec::new();
+        for _ in 0..4 {
+            let (chunk, status) = source.receive_vec_with_tag::<u64>(7);
+            assert_eq!(status.tag(), 7);
+            lengths.push(chunk.len());
+            received.extend(chunk);
+        }
+        assert_eq!(lengths, [3, 3, 3, 1]);
+        assert_eq!(received, msg);
+
+        let mut buf = vec![0; 10];
+        let status = world
+            .any_process()
+            .receive_into_chunked_with_tag(&mut buf[..], 3, 8);
+        assert_eq!(status.count(u64::equivalent_datatype()), 1);
+        assert_eq!(buf, msg);
+
+        let status = source.receive_into_chunked_with_tag(&mut buf[..0], 3, 9);
+        assert_eq!(status.count(u64::equivalent_datatype()), 0);
+
+        // Slices that fit into a single message are transferred by a single operation.
+        let mut buf = vec![0; 10];
+        let status = source.receive_into_large(&mut buf[..]);
+        assert_eq!(status.count(u64::equivalent_datatype()), 10);
+        assert_eq!(buf, msg);
+    }
+}
@@ -0,0 +1,38 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+
+    let next_process = world.process_at_rank((rank + 1) % size);
+    let previous_rank = (rank + size - 1) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    let greeting = format!("Grüße von Prozess {}", rank);
+
+    if rank == 0 {
+        next_process.send_str(&greeting);
+        let (msg, status) = previous_process.receive_string();
+        assert_eq!(Ok(format!("Grüße von Prozess {}", previous_rank)), msg);
+        assert_eq!(previous_rank, status.source_rank());
+    } else {
+        let (msg, status) = previous_process.receive_string();
+        next_process.send_str(&greeting);
+        assert_eq!(Ok(format!("Grüße von Prozess {}", previous_rank)), msg);
+        assert_eq!(previous_rank, status.source_rank());
+    }
+
+    world.barrier();
+
+    if rank == 0 {
+        world.process_at_rank(1).send(&[0xffu8, 0xfe][..]);
+    } else if rank == 1 {
+        let (msg, _) = world.process_at_rank(0).receive_string();
+        assert_eq!(vec![0xffu8, 0xfe], msg.unwrap_err().into_bytes());
+    }
+}
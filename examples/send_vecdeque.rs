@@ -0,0 +1,61 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use std::collections::VecDeque;
+
+use mpi::datatype::DequeBuffer;
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let size = world.size();
+    let rank = world.rank();
+    let next_rank = (rank + 1) % size;
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = (rank - 1 + size) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Fill the ring buffer, then pop from the front and push to the back so that its contents
+    // wrap around the end of its storage.
+    let mut deque = VecDeque::with_capacity(8);
+    let capacity = deque.capacity() as i32;
+    deque.extend((0..capacity).map(f64::from));
+    for _ in 0..4 {
+        deque.pop_front();
+    }
+    deque.extend((capacity..capacity + 4).map(f64::from));
+    let expected = (4..capacity + 4).map(f64::from).collect::<Vec<_>>();
+    assert!(!deque.as_slices().1.is_empty());
+
+    let mut received = vec![0.0; expected.len()];
+    p2p::send_receive_into(
+        &DequeBuffer::new(&deque),
+        &next_process,
+        &mut received[..],
+        &previous_process,
+    );
+    assert_eq!(received, expected);
+
+    world.barrier();
+
+    if rank == 0 {
+        for destination in 1..size {
+            world.process_at_rank(destination).send_vecdeque(&deque);
+        }
+    } else {
+        let (received, _) = world.process_at_rank(0).receive_vec::<f64>();
+        assert_eq!(received, expected);
+    }
+
+    // An empty deque is sent as an empty message.
+    let empty = VecDeque::<f64>::new();
+    let mut received = [0.0; 0];
+    p2p::send_receive_into(
+        &DequeBuffer::new(&empty),
+        &next_process,
+        &mut received[..],
+        &previous_process,
+    );
+}
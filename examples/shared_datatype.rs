@@ -0,0 +1,34 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype, View};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = (rank - 1 + size) % size;
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Every other element, used for both the request and the response.
+    let t = UserDatatype::vector(2, 1, 2, &i32::equivalent_datatype());
+
+    let request = [rank, -1, rank + 1, -1];
+    let mut response = [-1; 4];
+    {
+        let send = unsafe { View::with_count_and_datatype(&request[..], 1, &t) };
+        let mut receive = unsafe { MutView::with_count_and_datatype(&mut response[..], 1, &t) };
+        mpi::request::scope(|scope| {
+            let sreq = next_process.immediate_send(scope, &send);
+            previous_process.receive_into(&mut receive);
+            sreq.wait();
+        });
+    }
+
+    assert_eq!([previous_rank, -1, previous_rank + 1, -1], response);
+}
@@ -0,0 +1,55 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{SharedDatatype, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use std::thread;
+use std::time::Duration;
+
+fn drop_on_threads(datatype: &SharedDatatype) -> Vec<thread::JoinHandle<()>> {
+    (0..4u64)
+        .map(|i| {
+            let datatype = datatype.clone();
+            thread::spawn(move || {
+                // Drop the clones in a different order than they were created.
+                thread::sleep(Duration::from_millis(10 * (4 - i)));
+                drop(datatype);
+            })
+        })
+        .collect()
+}
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(mpi::Threading::Multiple).unwrap();
+
+    if threading != mpi::Threading::Multiple {
+        // Silently return - MPI implementation may not support `threading::Multiple`
+        return;
+    }
+
+    let world = universe.world();
+    let this_process = world.process_at_rank(world.rank());
+
+    let t = SharedDatatype::new(UserDatatype::vector(2, 1, 2, &i32::equivalent_datatype()));
+
+    // The datatype outlives all clones that are dropped on other threads.
+    for handle in drop_on_threads(&t) {
+        handle.join().unwrap();
+    }
+
+    let b1 = [1, 2, 3, 4];
+    let mut b2 = [0; 2];
+    {
+        let v = unsafe { View::with_count_and_datatype(&b1[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut b2[..], &this_process);
+    }
+    assert_eq!([1, 3], b2);
+
+    // The last clone to be dropped frees the datatype, here on one of the other threads.
+    let handles = drop_on_threads(&t);
+    drop(t);
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
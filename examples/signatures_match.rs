@@ -0,0 +1,46 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{signatures_match, UserDatatype};
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let int = i32::equivalent_datatype();
+    let double = f64::equivalent_datatype();
+
+    // The same sequence of four `i32`, laid out differently in memory
+    let strided = UserDatatype::vector(2, 2, 3, &int);
+    let contiguous = UserDatatype::contiguous(4, &int);
+    assert!(signatures_match(&strided, &contiguous));
+    assert!(signatures_match(&contiguous, &strided));
+    assert!(!signatures_match(
+        &contiguous,
+        &UserDatatype::contiguous(3, &int)
+    ));
+    assert!(!signatures_match(
+        &contiguous,
+        &UserDatatype::contiguous(4, &double)
+    ));
+
+    // Two records of an `i32` followed by an `f64`, once with and once without padding
+    let padded = UserDatatype::structured(&[1, 1], &[Address(0), Address(8)], &[int, double]);
+    let records = UserDatatype::contiguous(2, &padded);
+    let packed = UserDatatype::structured(
+        &[1, 1, 1, 1],
+        &[Address(0), Address(4), Address(12), Address(16)],
+        &[int, double, int, double],
+    );
+    assert!(signatures_match(&records, &packed));
+
+    // The order of the predefined datatypes matters.
+    let swapped = UserDatatype::structured(&[1, 1], &[Address(0), Address(8)], &[double, int]);
+    assert!(!signatures_match(&padded, &swapped));
+
+    // Blocks of the same predefined datatype
+    let split = UserDatatype::structured(&[1, 3], &[Address(0), Address(16)], &[int, int]);
+    assert!(signatures_match(&split, &contiguous));
+    assert!(signatures_match(&int, &UserDatatype::contiguous(1, &int)));
+}
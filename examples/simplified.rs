@@ -0,0 +1,56 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, Simplified, SimplifiedMut, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let pair = UserDatatype::contiguous(2, &f64::equivalent_datatype());
+    let triple_of_pairs = UserDatatype::contiguous(3, &pair);
+
+    let values = (0..12).map(f64::from).collect::<Vec<_>>();
+    let view = unsafe { View::with_count_and_datatype(&values[..], 2, &triple_of_pairs) };
+
+    let simplified = Simplified::new(&view);
+    assert!(simplified.is_simplified());
+    assert_eq!(simplified.count(), 12);
+    assert_eq!(
+        simplified.as_datatype().as_raw(),
+        f64::equivalent_datatype().as_raw()
+    );
+
+    // Sending the simplified buffer delivers the same data as sending the original one.
+    let mut original = vec![0.0; 12];
+    p2p::send_receive_into(&view, &this_process, &mut original[..], &this_process);
+    let mut fast = vec![0.0; 12];
+    p2p::send_receive_into(&simplified, &this_process, &mut fast[..], &this_process);
+    assert_eq!(original, values);
+    assert_eq!(fast, values);
+
+    let mut received = vec![0.0; 12];
+    {
+        let mut view =
+            unsafe { MutView::with_count_and_datatype(&mut received[..], 2, &triple_of_pairs) };
+        let mut simplified = SimplifiedMut::new(&mut view);
+        assert!(simplified.is_simplified());
+        p2p::send_receive_into(&values[..], &this_process, &mut simplified, &this_process);
+    }
+    assert_eq!(received, values);
+
+    // Datatypes with gaps are passed through unchanged.
+    let strided = UserDatatype::vector(2, 1, 2, &f64::equivalent_datatype());
+    let view = unsafe { View::with_count_and_datatype(&values[..], 1, &strided) };
+    let not_simplified = Simplified::new(&view);
+    assert!(!not_simplified.is_simplified());
+    assert_eq!(not_simplified.count(), 1);
+    let mut received = [0.0; 2];
+    p2p::send_receive_into(&not_simplified, &this_process, &mut received, &this_process);
+    assert_eq!(received, [0.0, 2.0]);
+
+    assert!(!Simplified::new(&values[..]).is_simplified());
+}
@@ -0,0 +1,41 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+const NX: i32 = 2;
+const NY: i32 = 3;
+const NZ: i32 = 4;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    // Each element holds its coordinates encoded as the digits `ijk`.
+    let mut array = Vec::new();
+    for i in 0..NX {
+        for j in 0..NY {
+            for k in 0..NZ {
+                array.push(i * 100 + j * 10 + k);
+            }
+        }
+    }
+
+    let k = 2;
+    let t = UserDatatype::slab_xy(NX, NY, NZ, k, &i32::equivalent_datatype());
+    assert_eq!(t.size(), NX * NY * 4);
+
+    let mut slab = vec![0; (NX * NY) as usize];
+    {
+        let v = unsafe { View::with_count_and_datatype(&array[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut slab[..], &this_process);
+    }
+
+    let expected = (0..NX)
+        .flat_map(|i| (0..NY).map(move |j| i * 100 + j * 10 + k))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, slab);
+}
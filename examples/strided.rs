@@ -0,0 +1,70 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, UserDatatype, View};
+use mpi::ffi::MPI_Aint;
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Address;
+use std::mem::size_of;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.process_at_rank(world.rank());
+
+    // Row-major 3 x 2 matrix
+    let matrix: [f64; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let elem = Address(size_of::<f64>() as MPI_Aint);
+
+    // The transposed 2 x 3 view of the matrix
+    let transposed = UserDatatype::strided(&[2, 3], &[elem, 2 * elem], &f64::equivalent_datatype());
+
+    let mut received = [0.0f64; 6];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], 1, &transposed) };
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!([1.0, 3.0, 5.0, 2.0, 4.0, 6.0], received);
+
+    // The first and last rows in reverse order, starting from the last row. The negative stride
+    // reaches back before the start of the slice, but stays within `matrix`.
+    let reversed = UserDatatype::strided(&[2, 2], &[-4 * elem, elem], &f64::equivalent_datatype());
+
+    let mut received = [0.0f64; 4];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[4..], 1, &reversed) };
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!([5.0, 6.0, 1.0, 2.0], received);
+
+    // The same views with the strides given in elements
+    let transposed = UserDatatype::from_strides(&[2, 3], &[1, 2], &f64::equivalent_datatype());
+    let mut received = [0.0f64; 6];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], 1, &transposed) };
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!([1.0, 3.0, 5.0, 2.0, 4.0, 6.0], received);
+
+    let reversed = UserDatatype::from_strides(&[2, 2], &[-4, 1], &f64::equivalent_datatype());
+    let mut received = [0.0f64; 4];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[4..], 1, &reversed) };
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!([5.0, 6.0, 1.0, 2.0], received);
+
+    // Receiving into the transposed layout undoes the transposition.
+    let mut restored = [0.0f64; 6];
+    {
+        let mut v = unsafe { MutView::with_count_and_datatype(&mut restored[..], 1, &transposed) };
+        p2p::send_receive_into(
+            &[1.0, 3.0, 5.0, 2.0, 4.0, 6.0][..],
+            &this_process,
+            &mut v,
+            &this_process,
+        );
+    }
+    assert_eq!(matrix, restored);
+}
@@ -0,0 +1,51 @@
+#![deny(warnings)]
+extern crate mpi;
+
+#[macro_use]
+extern crate memoffset;
+
+use mpi::ffi::MPI_Aint;
+use mpi::{
+    datatype::{StructTypeBuilder, UserDatatype},
+    traits::*,
+    Address,
+};
+
+#[derive(Default, Debug, PartialEq)]
+struct Particle {
+    id: u64,
+    position: [f64; 3],
+    alive: bool,
+}
+
+unsafe impl Equivalence for Particle {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        StructTypeBuilder::new()
+            .field::<u64>(Address(offset_of!(Particle, id) as MPI_Aint))
+            .array_field::<f64>(Address(offset_of!(Particle, position) as MPI_Aint), 3)
+            .field::<bool>(Address(offset_of!(Particle, alive) as MPI_Aint))
+            .build()
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    let expected = Particle {
+        id: 42,
+        position: [1.0, -2.0, 3.5],
+        alive: true,
+    };
+
+    if rank == 0 {
+        for destination in 1..world.size() {
+            world.process_at_rank(destination).send(&expected);
+        }
+    } else {
+        let (particle, _) = world.process_at_rank(0).receive::<Particle>();
+        assert_eq!(expected, particle);
+    }
+}
@@ -0,0 +1,57 @@
+#![deny(warnings)]
+#[macro_use]
+extern crate mpi;
+
+use mpi::ffi::MPI_Aint;
+use mpi::Address;
+use mpi::{datatype::UserDatatype, traits::*};
+
+#[repr(C)]
+#[derive(Default, Debug, PartialEq)]
+struct Particle {
+    id: i32,
+    mass: f64,
+    charge: f32,
+}
+
+unsafe impl Equivalence for Particle {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        struct_datatype!(Particle { id, mass, charge })
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let datatype = Particle::equivalent_datatype();
+    assert_eq!(datatype.size(), 4 + 8 + 4);
+    assert_eq!(
+        datatype.extent(),
+        Address(std::mem::size_of::<Particle>() as MPI_Aint)
+    );
+
+    // Fields that are not listed are not transferred.
+    let partial = struct_datatype!(Particle { mass });
+    assert_eq!(partial.size(), 8);
+
+    let mut particle = Particle::default();
+    if world.rank() == 0 {
+        particle = Particle {
+            id: 7,
+            mass: 1.5,
+            charge: -1.0,
+        };
+    }
+    world.process_at_rank(0).broadcast_into(&mut particle);
+
+    assert_eq!(
+        Particle {
+            id: 7,
+            mass: 1.5,
+            charge: -1.0,
+        },
+        particle
+    );
+}
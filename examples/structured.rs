@@ -1,6 +1,8 @@
 #![deny(warnings)]
 extern crate mpi;
 
+use mpi::ffi::MPI_Aint;
+use mpi::Address;
 use mpi::{datatype::UserDatatype, traits::*};
 use std::mem::size_of;
 
@@ -12,9 +14,9 @@ unsafe impl Equivalence for MyInts {
         UserDatatype::structured(
             &[1, 1, 1],
             &[
-                (size_of::<i32>() * 2) as mpi::Address,
-                size_of::<i32>() as mpi::Address,
-                0,
+                Address((size_of::<i32>() * 2) as MPI_Aint),
+                Address(size_of::<i32>() as MPI_Aint),
+                Address(0),
             ],
             &[i32::equivalent_datatype(); 3],
         )
@@ -0,0 +1,59 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::ffi::MPI_Aint;
+use mpi::{
+    datatype::{Datatype, UserDatatype},
+    traits::*,
+    Address,
+};
+use std::mem::size_of;
+
+#[repr(C)]
+#[derive(Default, Debug, PartialEq)]
+struct Layout {
+    pair: [i32; 2],
+    weight: f64,
+}
+
+unsafe impl Equivalence for Layout {
+    type Out = UserDatatype;
+    fn equivalent_datatype() -> Self::Out {
+        // The field types are a mix of system and user datatypes, collected behind trait objects.
+        let types: Vec<Box<dyn Datatype<DuplicatedDatatype = UserDatatype>>> = vec![
+            Box::new(UserDatatype::contiguous(2, &i32::equivalent_datatype())),
+            Box::new(f64::equivalent_datatype()),
+        ];
+
+        UserDatatype::structured(
+            &[1, 1],
+            &[Address(0), Address((size_of::<i32>() * 2) as MPI_Aint)],
+            &types[..],
+        )
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let root_process = world.process_at_rank(0);
+
+    if world.rank() == 0 {
+        root_process.broadcast_into(&mut Layout {
+            pair: [1, 2],
+            weight: 3.5,
+        });
+    } else {
+        let mut layout = Layout::default();
+        root_process.broadcast_into(&mut layout);
+
+        assert_eq!(
+            Layout {
+                pair: [1, 2],
+                weight: 3.5,
+            },
+            layout
+        );
+    }
+}
@@ -0,0 +1,29 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+
+    // Pairs of values are sent as one element each.
+    let t = UserDatatype::contiguous(2, &i32::equivalent_datatype());
+
+    if rank == 0 {
+        let buffer = (0..16).collect::<Vec<i32>>();
+        let view = unsafe { View::with_count_and_datatype(&buffer[..], 8, &t) };
+        let second_half = unsafe { view.sub(8..16) };
+        assert_eq!(4, second_half.count());
+
+        for destination in 1..world.size() {
+            world.process_at_rank(destination).send(&second_half);
+        }
+    } else {
+        let (msg, status) = world.process_at_rank(0).receive_vec::<i32>();
+        assert_eq!(4, status.count(&t));
+        assert_eq!((8..16).collect::<Vec<_>>(), msg);
+    }
+}
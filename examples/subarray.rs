@@ -0,0 +1,78 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{Order, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    // A 4 x 3 array holding the index of each element in memory
+    let array = (0..12).collect::<Vec<i32>>();
+
+    // In column-major order, element (i, j) is stored at index i + 4 * j.
+    let t = UserDatatype::fortran_subarray(&[4, 3], &[2, 2], &[1, 1], &i32::equivalent_datatype());
+    assert_eq!(t.size(), 4 * 4);
+    assert_eq!(t.extent(), Address(12 * 4));
+
+    let mut received = [0; 4];
+    {
+        let v = unsafe { View::with_count_and_datatype(&array[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut received, &this_process);
+    }
+    assert_eq!([5, 6, 9, 10], received);
+
+    // In row-major order, element (i, j) is stored at index 3 * i + j.
+    let t = UserDatatype::subarray(
+        &[4, 3],
+        &[2, 2],
+        &[1, 1],
+        Order::C,
+        &i32::equivalent_datatype(),
+    );
+    {
+        let v = unsafe { View::with_count_and_datatype(&array[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut received, &this_process);
+    }
+    assert_eq!([4, 5, 7, 8], received);
+
+    // Sub-blocks reaching beyond the array are rejected up front.
+    let error = UserDatatype::try_subarray(
+        &[4, 3],
+        &[2, 2],
+        &[1, 2],
+        Order::C,
+        &i32::equivalent_datatype(),
+    )
+    .unwrap_err();
+    assert_eq!(error.dim, 1);
+    assert_eq!((error.size, error.subsize, error.start), (3, 2, 2));
+    assert!(UserDatatype::try_subarray(
+        &[4, 3],
+        &[2, 2],
+        &[-1, 0],
+        Order::C,
+        &i32::equivalent_datatype()
+    )
+    .is_err());
+    let t = UserDatatype::try_subarray(
+        &[4, 3],
+        &[2, 2],
+        &[2, 1],
+        Order::C,
+        &i32::equivalent_datatype(),
+    )
+    .unwrap();
+    {
+        let v = unsafe { View::with_count_and_datatype(&array[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut received, &this_process);
+    }
+    assert_eq!([7, 8, 10, 11], received);
+
+    let rebuilt = UserDatatype::from_descriptor(&t.to_descriptor()).unwrap();
+    assert_eq!(rebuilt.to_descriptor(), t.to_descriptor());
+}
@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::supported_system_datatypes;
+use mpi::traits::*;
+use std::mem::size_of;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let datatypes = supported_system_datatypes();
+    for (name, datatype) in &datatypes {
+        println!(
+            "{}: {} ({} bytes)",
+            name,
+            datatype.type_name(),
+            datatype.size()
+        );
+    }
+
+    let size_of_type = |name: &str| {
+        datatypes
+            .iter()
+            .find(|&&(n, _)| n == name)
+            .map(|(_, datatype)| datatype.size() as usize)
+            .unwrap_or_else(|| panic!("{} is not supported", name))
+    };
+
+    assert_eq!(size_of_type("i8"), size_of::<i8>());
+    assert_eq!(size_of_type("i16"), size_of::<i16>());
+    assert_eq!(size_of_type("i32"), size_of::<i32>());
+    assert_eq!(size_of_type("i64"), size_of::<i64>());
+    assert_eq!(size_of_type("u8"), size_of::<u8>());
+    assert_eq!(size_of_type("u16"), size_of::<u16>());
+    assert_eq!(size_of_type("u32"), size_of::<u32>());
+    assert_eq!(size_of_type("u64"), size_of::<u64>());
+    assert_eq!(size_of_type("f32"), size_of::<f32>());
+    assert_eq!(size_of_type("f64"), size_of::<f64>());
+    assert_eq!(size_of_type("usize"), size_of::<usize>());
+}
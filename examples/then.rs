@@ -0,0 +1,46 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let a = UserDatatype::contiguous(2, &i32::equivalent_datatype());
+    let b = UserDatatype::contiguous(3, &i32::equivalent_datatype());
+    let c = UserDatatype::vector(2, 1, 2, &i32::equivalent_datatype());
+
+    let record = a.then(&b).then(&c);
+    assert_eq!(record.extent(), a.extent() + b.extent() + c.extent());
+    assert_eq!(record.size(), a.size() + b.size() + c.size());
+
+    // `c` skips every other element and its extent ends after its last block.
+    let buffer = [1, 2, 3, 4, 5, 6, -1, 7];
+    let mut received = [0; 7];
+    {
+        let v = unsafe { View::with_count_and_datatype(&buffer[..], 1, &record) };
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!([1, 2, 3, 4, 5, 6, 7], received);
+
+    // A first part with a nonzero lower bound, here the second element of a pair, is followed
+    // by `next` at its upper bound instead of its extent.
+    let second = UserDatatype::resized(&i32::equivalent_datatype(), Address(-4), Address(8));
+    assert_eq!(second.lower_bound(), Address(-4));
+    let pair = second.then(&a);
+    assert_eq!(pair.lower_bound(), Address(-4));
+    assert_eq!(pair.extent(), second.extent() + a.extent());
+
+    let buffer = [-1, 1, 2, 3];
+    let mut received = [0; 3];
+    {
+        let v = unsafe { View::with_count_and_datatype(&buffer[1..], 1, &pair) };
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!([1, 2, 3], received);
+}
@@ -0,0 +1,47 @@
+#![deny(warnings)]
+extern crate log;
+extern crate mpi;
+
+use log::{Log, Metadata, Record};
+use mpi::datatype::UserDatatype;
+use mpi::raw::AsRaw;
+use mpi::traits::*;
+use std::sync::Mutex;
+
+struct CapturingLogger(Mutex<Vec<String>>);
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger(Mutex::new(Vec::new()));
+
+fn main() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let _universe = mpi::initialize().unwrap();
+
+    let handle = {
+        let t = UserDatatype::contiguous(2, &f64::equivalent_datatype());
+        format!("{:?}", t.as_raw())
+    };
+
+    let messages = LOGGER.0.lock().unwrap();
+    assert_eq!(
+        vec![
+            format!("created datatype {} (Contiguous)", handle),
+            format!("committed datatype {} (Contiguous)", handle),
+            format!("freeing datatype {} (Contiguous)", handle),
+        ],
+        *messages
+    );
+}
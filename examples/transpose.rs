@@ -0,0 +1,29 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+const ROWS: usize = 2;
+const COLS: usize = 3;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.process_at_rank(world.rank());
+
+    // Row-major 2 x 3 matrix
+    let matrix: [f64; ROWS * COLS] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+    let t = UserDatatype::transpose_2d(ROWS as i32, COLS as i32, &f64::equivalent_datatype());
+
+    let mut transposed = [0.0f64; ROWS * COLS];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut transposed[..], &this_process);
+    }
+
+    // Row-major 3 x 2 matrix, i.e. the original in column-major order
+    assert_eq!([1.0, 4.0, 2.0, 5.0, 3.0, 6.0], transposed);
+}
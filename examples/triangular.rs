@@ -0,0 +1,50 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{MutView, Triangle, UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::topology::Rank;
+use mpi::traits::*;
+use mpi::Count;
+
+const N: usize = 3;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = if rank + 1 < size { rank + 1 } else { 0 };
+    let next_process = world.process_at_rank(next_rank);
+    let previous_rank = if rank > 0 { rank - 1 } else { size - 1 };
+    let previous_process = world.process_at_rank(previous_rank);
+
+    // Row-major N x N matrix
+    let matrix = (1..=(N * N) as Rank)
+        .map(|x| rank * 100 + x)
+        .collect::<Vec<_>>();
+
+    let t = UserDatatype::triangular(N as Count, Triangle::Lower, &Rank::equivalent_datatype());
+    let mut lower = vec![-1; N * N];
+    {
+        let v1 = unsafe { View::with_count_and_datatype(&matrix[..], 1, &t) };
+        let mut v2 = unsafe { MutView::with_count_and_datatype(&mut lower[..], 1, &t) };
+        p2p::send_receive_into(&v1, &next_process, &mut v2, &previous_process);
+    }
+
+    // Only the elements on and below the diagonal arrive.
+    let o = previous_rank * 100;
+    assert_eq!(
+        vec![o + 1, -1, -1, o + 4, o + 5, -1, o + 7, o + 8, o + 9],
+        lower
+    );
+
+    let t = UserDatatype::triangular(N as Count, Triangle::Upper, &Rank::equivalent_datatype());
+    let mut upper = [-1; N * (N + 1) / 2];
+    {
+        let v = unsafe { View::with_count_and_datatype(&matrix[..], 1, &t) };
+        p2p::send_receive_into(&v, &next_process, &mut upper[..], &previous_process);
+    }
+    assert_eq!([o + 1, o + 2, o + 3, o + 5, o + 6, o + 9], upper);
+}
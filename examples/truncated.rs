@@ -0,0 +1,40 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let values = (0..8).collect::<Vec<i32>>();
+
+    // Send the first half of a buffer.
+    let half = values[..].truncated(4);
+    assert_eq!(half.count(), 4);
+    let mut received = [-1; 8];
+    p2p::send_receive_into(&half, &this_process, &mut received[..4], &this_process);
+    assert_eq!(received, [0, 1, 2, 3, -1, -1, -1, -1]);
+
+    // The datatype is preserved, only the count changes.
+    let pair = UserDatatype::contiguous(2, &i32::equivalent_datatype());
+    let view = unsafe { View::with_count_and_datatype(&values[..], 4, &pair) };
+    let first_pair = view.truncated(1);
+    assert_eq!(first_pair.as_datatype().as_raw(), pair.as_raw());
+    let mut received = [-1; 8];
+    p2p::send_receive_into(&first_pair, &this_process, &mut received[..], &this_process);
+    assert_eq!(received, [0, 1, -1, -1, -1, -1, -1, -1]);
+
+    // Receive into the second half of a buffer only.
+    let mut received = [-1; 8];
+    p2p::send_receive_into(
+        &values[4..6],
+        &this_process,
+        &mut received[4..].truncated_mut(2),
+        &this_process,
+    );
+    assert_eq!(received, [-1, -1, -1, -1, 4, 5, -1, -1]);
+}
@@ -0,0 +1,37 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UncommittedUserDatatype, View};
+use mpi::ffi;
+use mpi::point_to_point as p2p;
+use mpi::raw::AsRaw;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    // Let MPI report errors instead of aborting.
+    unsafe {
+        ffi::MPI_Comm_set_errhandler(world.as_raw(), ffi::RSMPI_ERRORS_RETURN);
+        ffi::MPI_Comm_set_errhandler(ffi::RSMPI_COMM_SELF, ffi::RSMPI_ERRORS_RETURN);
+    }
+
+    let pair = UncommittedUserDatatype::contiguous(2, &i32::equivalent_datatype())
+        .try_commit()
+        .expect("committing a valid datatype succeeds");
+    assert_eq!(pair.size(), 8);
+
+    let mut received = [0; 2];
+    {
+        let v = unsafe { View::with_count_and_datatype(&[3, 4][..], 1, &pair) };
+        p2p::send_receive_into(&v, &this_process, &mut received, &this_process);
+    }
+    assert_eq!(received, [3, 4]);
+
+    unsafe {
+        ffi::MPI_Comm_set_errhandler(world.as_raw(), ffi::RSMPI_ERRORS_ARE_FATAL);
+        ffi::MPI_Comm_set_errhandler(ffi::RSMPI_COMM_SELF, ffi::RSMPI_ERRORS_ARE_FATAL);
+    }
+}
@@ -0,0 +1,31 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{DatatypeRef, UncommittedUserDatatype, UserDatatype};
+use mpi::raw::{AsRaw, FromRaw};
+use mpi::traits::*;
+use std::panic;
+
+fn main() {
+    let _universe = mpi::initialize().unwrap();
+
+    let uncommitted = UncommittedUserDatatype::contiguous(2, &f64::equivalent_datatype());
+
+    // The check is only performed in debug builds.
+    if cfg!(debug_assertions) {
+        let handle = uncommitted.as_raw();
+        let result = panic::catch_unwind(|| unsafe { DatatypeRef::from_raw(handle) });
+        assert!(result.is_err());
+
+        // Duplicates of an uncommitted datatype are uncommitted as well.
+        let duplicate = uncommitted.dup();
+        let handle = duplicate.as_raw();
+        let result = panic::catch_unwind(|| unsafe { DatatypeRef::from_raw(handle) });
+        assert!(result.is_err());
+    }
+
+    // Once committed, the handle can be used.
+    let committed: UserDatatype = uncommitted.commit();
+    let datatype = unsafe { DatatypeRef::from_raw(committed.as_raw()) };
+    assert_eq!(datatype.size(), 16);
+}
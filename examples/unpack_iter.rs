@@ -0,0 +1,36 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    // Values packed one at a time, back to back
+    let values = [7i64, -1, 42, 0, 5];
+    let mut packed = vec![
+        0u8;
+        world.pack_size(values.len() as mpi::Count, &i64::equivalent_datatype())
+            as usize
+    ];
+    let mut position = 0;
+    for value in &values {
+        position = world.pack_into(value, &mut packed[..], position);
+    }
+    packed.truncate(position as usize);
+
+    // Every value comes out exactly once, then the iterator stays exhausted.
+    let mut unpacked = unsafe { world.unpack_iter::<i64>(&packed) };
+    for &expected in &values {
+        assert_eq!(Some(expected), unpacked.next());
+    }
+    assert_eq!(None, unpacked.next());
+    assert_eq!(None, unpacked.next());
+
+    assert_eq!(
+        values.len(),
+        unsafe { world.unpack_iter::<i64>(&packed) }.count()
+    );
+    assert_eq!(0, unsafe { world.unpack_iter::<i64>(&[]) }.count());
+}
@@ -0,0 +1,54 @@
+#![deny(warnings)]
+#[macro_use]
+extern crate mpi;
+
+use mpi::collective::UserOperation;
+use mpi::datatype::{cached_datatype, DatatypeRef};
+use mpi::traits::*;
+
+#[repr(C)]
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+struct Stats {
+    count: i32,
+    sum: f64,
+    max: f64,
+}
+
+unsafe impl Equivalence for Stats {
+    type Out = DatatypeRef<'static>;
+    fn equivalent_datatype() -> Self::Out {
+        cached_datatype::<Self, _>(|| struct_datatype!(Stats { count, sum, max }))
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let op = UserOperation::typed(true, |x: &[Stats], y: &mut [Stats]| {
+        for (x_i, y_i) in x.iter().zip(y) {
+            y_i.count += x_i.count;
+            y_i.sum += x_i.sum;
+            y_i.max = y_i.max.max(x_i.max);
+        }
+    });
+
+    let local = Stats {
+        count: 1,
+        sum: f64::from(rank),
+        max: f64::from(rank),
+    };
+    let mut total = Stats::default();
+    world.all_reduce_into(&local, &mut total, &op);
+
+    assert_eq!(
+        total,
+        Stats {
+            count: size,
+            sum: f64::from(size * (size - 1) / 2),
+            max: f64::from(size - 1),
+        }
+    );
+}
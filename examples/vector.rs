@@ -1,7 +1,7 @@
 #![deny(warnings)]
 extern crate mpi;
 
-use mpi::datatype::{MutView, UserDatatype, View};
+use mpi::datatype::{MutView, OverlapError, UserDatatype, View};
 use mpi::point_to_point as p2p;
 use mpi::topology::Rank;
 use mpi::traits::*;
@@ -41,4 +41,16 @@ fn main() {
         .take(6)
         .collect::<Vec<_>>();
     assert_eq!(b3, b2);
+
+    // A stride shorter than the block length makes consecutive blocks overlap.
+    let overlapping = UserDatatype::try_vector(2, 3, 2, &Rank::equivalent_datatype(), false);
+    assert_eq!(
+        overlapping.unwrap_err(),
+        OverlapError {
+            first: 0,
+            second: 1
+        }
+    );
+    assert!(UserDatatype::try_vector(2, 3, 2, &Rank::equivalent_datatype(), true).is_ok());
+    assert!(UserDatatype::try_vector(2, 2, 3, &Rank::equivalent_datatype(), false).is_ok());
 }
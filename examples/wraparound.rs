@@ -0,0 +1,34 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let ring = (0..8).collect::<Vec<i32>>();
+
+    // Five elements starting at index 6 wrap around after two elements.
+    let t = UserDatatype::wraparound(8, 6, 5, &i32::equivalent_datatype());
+    assert_eq!(t.size(), 5 * 4);
+
+    let mut received = [0; 5];
+    {
+        let v = unsafe { View::with_count_and_datatype(&ring[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut received, &this_process);
+    }
+    assert_eq!([6, 7, 0, 1, 2], received);
+
+    // Without wrapping around, a single block remains.
+    let t = UserDatatype::wraparound(8, 2, 5, &i32::equivalent_datatype());
+    assert_eq!(t.contents().unwrap().integers, vec![1, 5, 2]);
+    {
+        let v = unsafe { View::with_count_and_datatype(&ring[..], 1, &t) };
+        p2p::send_receive_into(&v, &this_process, &mut received, &this_process);
+    }
+    assert_eq!([2, 3, 4, 5, 6], received);
+}
@@ -0,0 +1,39 @@
+#![deny(warnings)]
+extern crate mpi;
+
+use mpi::datatype::{UserDatatype, View};
+use mpi::point_to_point as p2p;
+use mpi::traits::*;
+use mpi::Address;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let this_process = world.this_process();
+
+    let marker = UserDatatype::resized(&i32::equivalent_datatype(), Address(0), Address(0));
+    assert_eq!(marker.extent(), Address(0));
+    assert_eq!(marker.lower_bound(), Address(0));
+    assert_eq!(marker.size(), 4);
+    assert_eq!(marker.bytes_for(10), Address(0));
+
+    // All three elements of the contiguous datatype are located at the same address.
+    let repeated = UserDatatype::contiguous(3, &marker);
+    assert_eq!(repeated.extent(), Address(0));
+    assert_eq!(repeated.size(), 12);
+
+    // Sending it repeats the same value, receiving it needs distinct memory.
+    let value = [7];
+    let mut received = [0; 3];
+    {
+        let v = unsafe { View::with_count_and_datatype(&value[..], 1, &repeated) };
+        p2p::send_receive_into(&v, &this_process, &mut received[..], &this_process);
+    }
+    assert_eq!(received, [7, 7, 7]);
+
+    // A zero extent can also be placed away from the start of the buffer.
+    let shifted = UserDatatype::resized(&i32::equivalent_datatype(), Address(8), Address(0));
+    assert_eq!(shifted.extent(), Address(0));
+    assert_eq!(shifted.lower_bound(), Address(8));
+    assert_eq!(shifted.true_extent(), Address(4));
+}
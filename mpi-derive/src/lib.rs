@@ -34,7 +34,7 @@ fn equivalence_for_tuple_field(type_tuple: &syn::TypeTuple) -> TokenStream2 {
     quote! {
         &::mpi::datatype::UncommittedUserDatatype::structured(
             &[#(#field_blocklengths as ::mpi::Count),*],
-            &[#(::mpi::internal::memoffset::offset_of_tuple!(#type_tuple, #fields) as ::mpi::Address),*],
+            &[#(::mpi::Address(::mpi::internal::memoffset::offset_of_tuple!(#type_tuple, #fields) as ::mpi::ffi::MPI_Aint)),*],
             &[#(::mpi::datatype::UncommittedDatatypeRef::from(#field_datatypes)),*],
         )
     }
@@ -85,21 +85,17 @@ fn equivalence_for_struct(ast: &syn::DeriveInput, fields: &Fields) -> TokenStrea
         unsafe impl ::mpi::datatype::Equivalence for #ident {
             type Out = ::mpi::datatype::DatatypeRef<'static>;
             fn equivalent_datatype() -> Self::Out {
-                use ::mpi::internal::once_cell::sync::Lazy;
-
-                static DATATYPE: Lazy<::mpi::datatype::UserDatatype> = Lazy::new(|| {
+                ::mpi::datatype::cached_datatype::<Self, _>(|| {
                     ::mpi::datatype::internal::check_derive_equivalence_universe_state(#ident_str);
 
                     ::mpi::datatype::UserDatatype::structured::<
                         ::mpi::datatype::UncommittedDatatypeRef,
                     >(
                         &[#(#field_blocklengths as ::mpi::Count),*],
-                        &[#(::mpi::internal::memoffset::offset_of!(#ident, #field_names) as ::mpi::Address),*],
+                        &[#(::mpi::Address(::mpi::internal::memoffset::offset_of!(#ident, #field_names) as ::mpi::ffi::MPI_Aint)),*],
                         &[#(::mpi::datatype::UncommittedDatatypeRef::from(#field_datatypes)),*],
                     )
-                });
-
-                DATATYPE.as_ref()
+                })
             }
         }
     }
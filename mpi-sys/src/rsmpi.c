@@ -15,6 +15,9 @@ const MPI_Datatype RSMPI_UINT16_T = MPI_UINT16_T;
 const MPI_Datatype RSMPI_UINT32_T = MPI_UINT32_T;
 const MPI_Datatype RSMPI_UINT64_T = MPI_UINT64_T;
 
+const MPI_Datatype RSMPI_BYTE = MPI_BYTE;
+const MPI_Datatype RSMPI_PACKED = MPI_PACKED;
+
 const MPI_Datatype RSMPI_DATATYPE_NULL = MPI_DATATYPE_NULL;
 
 const MPI_Comm RSMPI_COMM_WORLD = MPI_COMM_WORLD;
@@ -39,6 +42,12 @@ const MPI_Request RSMPI_REQUEST_NULL = MPI_REQUEST_NULL;
 MPI_Status* const RSMPI_STATUS_IGNORE = MPI_STATUS_IGNORE;
 MPI_Status* const RSMPI_STATUSES_IGNORE = MPI_STATUSES_IGNORE;
 
+void* const RSMPI_IN_PLACE = MPI_IN_PLACE;
+void* const RSMPI_BOTTOM = MPI_BOTTOM;
+
+MPI_Type_copy_attr_function* const RSMPI_TYPE_DUP_FN = MPI_TYPE_DUP_FN;
+MPI_Type_delete_attr_function* const RSMPI_TYPE_NULL_DELETE_FN = MPI_TYPE_NULL_DELETE_FN;
+
 const int RSMPI_IDENT = MPI_IDENT;
 const int RSMPI_CONGRUENT = MPI_CONGRUENT;
 const int RSMPI_SIMILAR = MPI_SIMILAR;
@@ -49,6 +58,26 @@ const int RSMPI_THREAD_FUNNELED = MPI_THREAD_FUNNELED;
 const int RSMPI_THREAD_SERIALIZED = MPI_THREAD_SERIALIZED;
 const int RSMPI_THREAD_MULTIPLE = MPI_THREAD_MULTIPLE;
 
+const int RSMPI_COMBINER_NAMED = MPI_COMBINER_NAMED;
+const int RSMPI_COMBINER_DUP = MPI_COMBINER_DUP;
+const int RSMPI_COMBINER_CONTIGUOUS = MPI_COMBINER_CONTIGUOUS;
+const int RSMPI_COMBINER_VECTOR = MPI_COMBINER_VECTOR;
+const int RSMPI_COMBINER_HVECTOR = MPI_COMBINER_HVECTOR;
+const int RSMPI_COMBINER_INDEXED = MPI_COMBINER_INDEXED;
+const int RSMPI_COMBINER_HINDEXED = MPI_COMBINER_HINDEXED;
+const int RSMPI_COMBINER_INDEXED_BLOCK = MPI_COMBINER_INDEXED_BLOCK;
+const int RSMPI_COMBINER_HINDEXED_BLOCK = MPI_COMBINER_HINDEXED_BLOCK;
+const int RSMPI_COMBINER_STRUCT = MPI_COMBINER_STRUCT;
+const int RSMPI_COMBINER_SUBARRAY = MPI_COMBINER_SUBARRAY;
+const int RSMPI_COMBINER_DARRAY = MPI_COMBINER_DARRAY;
+const int RSMPI_COMBINER_F90_REAL = MPI_COMBINER_F90_REAL;
+const int RSMPI_COMBINER_F90_COMPLEX = MPI_COMBINER_F90_COMPLEX;
+const int RSMPI_COMBINER_F90_INTEGER = MPI_COMBINER_F90_INTEGER;
+const int RSMPI_COMBINER_RESIZED = MPI_COMBINER_RESIZED;
+
+const int RSMPI_ORDER_C = MPI_ORDER_C;
+const int RSMPI_ORDER_FORTRAN = MPI_ORDER_FORTRAN;
+
 const int RSMPI_GRAPH = MPI_GRAPH;
 const int RSMPI_CART = MPI_CART;
 const int RSMPI_DIST_GRAPH = MPI_DIST_GRAPH;
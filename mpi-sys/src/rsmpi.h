@@ -21,6 +21,9 @@ extern const MPI_Datatype RSMPI_UINT16_T;
 extern const MPI_Datatype RSMPI_UINT32_T;
 extern const MPI_Datatype RSMPI_UINT64_T;
 
+extern const MPI_Datatype RSMPI_BYTE;
+extern const MPI_Datatype RSMPI_PACKED;
+
 extern const MPI_Datatype RSMPI_DATATYPE_NULL;
 
 extern const MPI_Comm RSMPI_COMM_WORLD;
@@ -45,6 +48,12 @@ extern const MPI_Request RSMPI_REQUEST_NULL;
 extern MPI_Status* const RSMPI_STATUS_IGNORE;
 extern MPI_Status* const RSMPI_STATUSES_IGNORE;
 
+extern void* const RSMPI_IN_PLACE;
+extern void* const RSMPI_BOTTOM;
+
+extern MPI_Type_copy_attr_function* const RSMPI_TYPE_DUP_FN;
+extern MPI_Type_delete_attr_function* const RSMPI_TYPE_NULL_DELETE_FN;
+
 extern const int RSMPI_IDENT;
 extern const int RSMPI_CONGRUENT;
 extern const int RSMPI_SIMILAR;
@@ -55,6 +64,26 @@ extern const int RSMPI_THREAD_FUNNELED;
 extern const int RSMPI_THREAD_SERIALIZED;
 extern const int RSMPI_THREAD_MULTIPLE;
 
+extern const int RSMPI_COMBINER_NAMED;
+extern const int RSMPI_COMBINER_DUP;
+extern const int RSMPI_COMBINER_CONTIGUOUS;
+extern const int RSMPI_COMBINER_VECTOR;
+extern const int RSMPI_COMBINER_HVECTOR;
+extern const int RSMPI_COMBINER_INDEXED;
+extern const int RSMPI_COMBINER_HINDEXED;
+extern const int RSMPI_COMBINER_INDEXED_BLOCK;
+extern const int RSMPI_COMBINER_HINDEXED_BLOCK;
+extern const int RSMPI_COMBINER_STRUCT;
+extern const int RSMPI_COMBINER_SUBARRAY;
+extern const int RSMPI_COMBINER_DARRAY;
+extern const int RSMPI_COMBINER_F90_REAL;
+extern const int RSMPI_COMBINER_F90_COMPLEX;
+extern const int RSMPI_COMBINER_F90_INTEGER;
+extern const int RSMPI_COMBINER_RESIZED;
+
+extern const int RSMPI_ORDER_C;
+extern const int RSMPI_ORDER_FORTRAN;
+
 extern const int RSMPI_GRAPH;
 extern const int RSMPI_CART;
 extern const int RSMPI_DIST_GRAPH;
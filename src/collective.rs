@@ -88,6 +88,40 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Gather contents of buffers on all participating processes, in place.
+    ///
+    /// `buffer` is divided into one block of equal size per process. Each process contributes the
+    /// contents of its own block, the block with the index of its rank, and after the call
+    /// completes `buffer` contains the blocks of all processes on all ranks.
+    ///
+    /// This passes `MPI_IN_PLACE` as the send buffer, saving a separate buffer for the input.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/in_place.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.7
+    fn all_gather_in_place<B: ?Sized>(&self, buffer: &mut B)
+    where
+        B: BufferMut,
+    {
+        let in_place = unsafe_extern_static!(ffi::RSMPI_IN_PLACE);
+        let null = unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL);
+        unsafe {
+            ffi::MPI_Allgather(
+                in_place,
+                0,
+                null,
+                buffer.pointer_mut(),
+                buffer.count() / self.size(),
+                buffer.as_datatype().as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
     /// Gather contents of buffers on all participating processes.
     ///
     /// After the call completes, the contents of the send `Buffer`s on all processes will be
@@ -208,6 +242,36 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Performs a global reduction under the operation `op` of the input data in `buffer` and
+    /// replaces the contents of `buffer` with the result on all processes.
+    ///
+    /// This passes `MPI_IN_PLACE` as the send buffer, saving a separate buffer for the input.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/in_place.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6
+    fn all_reduce_in_place<B: ?Sized, O>(&self, buffer: &mut B, op: O)
+    where
+        B: BufferMut,
+        O: Operation,
+    {
+        let in_place = unsafe_extern_static!(ffi::RSMPI_IN_PLACE);
+        unsafe {
+            ffi::MPI_Allreduce(
+                in_place,
+                buffer.pointer_mut(),
+                buffer.count(),
+                buffer.as_datatype().as_raw(),
+                op.as_raw(),
+                self.as_raw(),
+            );
+        }
+    }
+
     /// Performs an element-wise global reduction under the operation `op` of the input data in
     /// `sendbuf` and scatters the result into equal sized blocks in the receive buffers on all
     /// processes.
@@ -762,6 +826,43 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Gather contents of buffers on `Root`, in place.
+    ///
+    /// The contribution of `Root` is the block of `buffer` with the index of its rank. After the
+    /// call completes, `buffer` contains the blocks of all ranks. The other processes call
+    /// `gather_into()` as usual.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/in_place.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_into_root_in_place<B: ?Sized>(&self, buffer: &mut B)
+    where
+        B: BufferMut,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let in_place = unsafe_extern_static!(ffi::RSMPI_IN_PLACE);
+        let null = unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL);
+        unsafe {
+            let recvcount = buffer.count() / self.as_communicator().size();
+            ffi::MPI_Gather(
+                in_place,
+                0,
+                null,
+                buffer.pointer_mut(),
+                recvcount,
+                buffer.as_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
     /// Gather contents of buffers on `Root`.
     ///
     /// After the call completes, the contents of the `Buffer`s on all ranks will be
@@ -1047,6 +1148,39 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Performs a global reduction under the operation `op` of the input data in `buffer` and
+    /// replaces the contents of `buffer` with the result on `Root`. The other processes call
+    /// `reduce_into()` as usual.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/in_place.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    fn reduce_into_root_in_place<B: ?Sized, O>(&self, buffer: &mut B, op: O)
+    where
+        B: BufferMut,
+        O: Operation,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        let in_place = unsafe_extern_static!(ffi::RSMPI_IN_PLACE);
+        unsafe {
+            ffi::MPI_Reduce(
+                in_place,
+                buffer.pointer_mut(),
+                buffer.count(),
+                buffer.as_datatype().as_raw(),
+                op.as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
     /// Initiate broadcast of a value from the `Root` process to all other processes.
     ///
     /// # Examples
@@ -1713,6 +1847,40 @@ impl<'a> UserOperation<'a> {
         }
     }
 
+    /// Creates an associative and possibly commutative operation on elements of type `T` using a
+    /// closure.
+    ///
+    /// Like `new`, but the closure receives `invec` and `inoutvec` as slices of `T`. This allows
+    /// reductions on types that the predefined operations do not support, e.g. structs with
+    /// `#[derive(Equivalence)]`.
+    ///
+    /// The type of the buffers is recognized by the handle of the datatype equivalent to `T`, so
+    /// `T::equivalent_datatype()` has to return the same datatype on every call, as is the case
+    /// for system datatypes, `#[derive(Equivalence)]` and `cached_datatype()`. If the operation is
+    /// used with any other datatype, the program will abort.
+    ///
+    /// # Examples
+    /// See `examples/user_operation_typed.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.5
+    pub fn typed<T, F>(commute: bool, function: F) -> Self
+    where
+        T: Equivalence,
+        F: Fn(&[T], &mut [T]) + Sync + 'a,
+    {
+        Self::new(commute, move |invec, inoutvec| {
+            let invec = invec
+                .downcast::<T>()
+                .expect("User operation applied to a buffer of a different type.");
+            let inoutvec = inoutvec
+                .downcast::<T>()
+                .expect("User operation applied to a buffer of a different type.");
+            function(invec, inoutvec)
+        })
+    }
+
     /// Creates a `UserOperation` from raw parts.
     ///
     /// Here, `anchor` is an arbitrary object that is stored alongside the `MPI_Op`.
@@ -1736,7 +1904,7 @@ unsafe fn user_operation_landing_pad<F>(
     F: Fn(DynBuffer, DynBufferMut),
 {
     let len = *len;
-    let datatype = DatatypeRef::from_raw(*datatype);
+    let datatype = DatatypeRef::from_raw_unchecked(*datatype);
     if len == 0 {
         // precautionary measure: ensure pointers are not null
         invec = [].as_mut_ptr();
@@ -44,48 +44,197 @@
 //! A `Buffer` describes a specific piece of data in memory that MPI should operate on. In addition
 //! to specifying the datatype of the data. It knows the address in memory where the data begins
 //! and how many instances of the datatype are contained in the data. The `Buffer` trait is
-//! implemented for slices that contain types implementing `Equivalence`.
+//! implemented for types implementing `Equivalence`, as a buffer containing a single instance, and
+//! for slices of them. So `&x` and `&[x][..]` can be used interchangeably wherever a `Buffer` is
+//! expected.
 //!
 //! In order to use arbitrary datatypes to describe the contents of a slice, the `View` type is
 //! provided. However, since it can be used to instruct the underlying MPI implementation to
 //! rummage around arbitrary parts of memory, its constructors are currently marked unsafe.
 //!
+//! ## Thread Safety
+//! Constructing, committing and freeing datatypes are MPI calls like any other, so they are
+//! subject to the threading level returned by `initialize_with_threading()`. With
+//! `Threading::Multiple` datatypes can be constructed from any number of threads at once. With
+//! `Threading::Serialized` the user has to make sure that no two threads construct or free
+//! datatypes at the same time, and with `Threading::Funneled` or `Threading::Single` only the main
+//! thread may do so.
+//!
+//! This includes the first call to `equivalent_datatype()` for types whose datatype is cached by
+//! `cached_datatype()`, e.g. those using `#[derive(Equivalence)]`, which happens implicitly when
+//! communicating them. The cache itself is synchronized: concurrent first uses of the same type
+//! from several threads all end up with the same datatype.
+//!
 //! # Unfinished features
 //!
-//! - **4.1.3**: Subarray datatype constructors, `MPI_Type_create_subarray()`,
 //! - **4.1.4**: Distributed array datatype constructors, `MPI_Type_create_darray()`
 //! - **4.1.5**: Address and size functions, `MPI_Get_address()`, `MPI_Aint_add()`,
-//! `MPI_Aint_diff()`, `MPI_Type_size()`, `MPI_Type_size_x()`
-//! - **4.1.7**: Extent and bounds of datatypes: `MPI_Type_get_extent()`,
-//! `MPI_Type_get_extent_x()`, `MPI_Type_create_resized()`
-//! - **4.1.8**: True extent of datatypes, `MPI_Type_get_true_extent()`,
-//! `MPI_Type_get_true_extent_x()`
-//! - **4.1.11**: `MPI_Get_elements()`, `MPI_Get_elements_x()`
-//! - **4.1.13**: Decoding a datatype, `MPI_Type_get_envelope()`, `MPI_Type_get_contents()`
+//! `MPI_Aint_diff()`
+//! - **4.1.7**: Extent and bounds of datatypes: `MPI_Type_get_extent_x()`
+//! - **4.1.8**: True extent of datatypes, `MPI_Type_get_true_extent_x()`
+//! - **4.1.11**: `MPI_Get_elements_x()`
 //! - **4.3**: Canonical pack and unpack, `MPI_Pack_external()`, `MPI_Unpack_external()`,
 //! `MPI_Pack_external_size()`
 
-use std::borrow::Borrow;
-use std::marker::PhantomData;
-use std::os::raw::c_void;
-use std::{mem, slice};
+use core::borrow::Borrow;
+use core::ffi::c_void;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{size_of, size_of_val, MaybeUninit};
+use core::ops::Range;
+#[cfg(debug_assertions)]
+use core::ptr;
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+use core::{iter, mem, slice};
+
+use std::alloc;
+use std::any::TypeId;
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::sync::{Arc, Mutex};
 
 use conv::ConvUtil;
+use once_cell::sync::Lazy;
+#[cfg(debug_assertions)]
+use once_cell::sync::OnceCell;
+use once_cell::unsync;
+use smallvec::SmallVec;
 
-use super::{Address, Count};
+use super::{Address, Count, LargeCount};
 
 use crate::ffi;
-use crate::ffi::MPI_Datatype;
+use crate::ffi::{MPI_Aint, MPI_Datatype};
 
 use crate::raw::traits::*;
+use crate::topology::Communicator;
+
+use crate::{with_uninitialized, with_uninitialized2};
+
+/// Logs `$operation` on `$datatype` together with its handle and combiner, if the `trace` feature
+/// is enabled, and does nothing otherwise.
+macro_rules! trace_datatype {
+    ($operation:expr, $datatype:expr) => {
+        #[cfg(feature = "trace")]
+        {
+            let datatype = &$datatype;
+            log::trace!(
+                "{} datatype {:?} ({:?})",
+                $operation,
+                datatype.as_raw(),
+                datatype.envelope().combiner
+            );
+        }
+    };
+}
+
+/// The attribute key used to mark datatypes as uncommitted, only in debug builds, to catch
+/// uncommitted datatypes being turned into committed ones via `from_raw()`.
+///
+/// The mark is stored with the MPI datatype object itself, so it is seen by every handle MPI hands
+/// out for it, e.g. when decoding a datatype, and copied by `MPI_Type_dup()` together with the
+/// committed state. A flag in the wrappers would not be, as `from_raw()` is handed a raw handle
+/// without knowing which wrapper, if any, it came from.
+#[cfg(debug_assertions)]
+static UNCOMMITTED_KEYVAL: OnceCell<c_int> = OnceCell::new();
+
+/// The attribute key in `UNCOMMITTED_KEYVAL`, created on first use
+#[cfg(debug_assertions)]
+fn uncommitted_keyval() -> c_int {
+    *UNCOMMITTED_KEYVAL.get_or_init(|| {
+        let copy = unsafe_extern_static!(ffi::RSMPI_TYPE_DUP_FN);
+        let delete = unsafe_extern_static!(ffi::RSMPI_TYPE_NULL_DELETE_FN);
+        unsafe {
+            with_uninitialized(|keyval| {
+                ffi::MPI_Type_create_keyval(copy, delete, keyval, ptr::null_mut())
+            })
+        }
+        .1
+    })
+}
+
+/// Frees the attribute key in `UNCOMMITTED_KEYVAL`, if it was ever created
+///
+/// Must be called before `MPI_Finalize()`.
+pub(crate) fn free_uncommitted_keyval() {
+    #[cfg(debug_assertions)]
+    {
+        if let Some(mut keyval) = UNCOMMITTED_KEYVAL.get().copied() {
+            unsafe {
+                ffi::MPI_Type_free_keyval(&mut keyval);
+            }
+        }
+    }
+}
+
+/// Marks the datatype `handle` as uncommitted
+fn mark_uncommitted(handle: MPI_Datatype) {
+    #[cfg(debug_assertions)]
+    unsafe {
+        ffi::MPI_Type_set_attr(handle, uncommitted_keyval(), ptr::null_mut());
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = handle;
+}
+
+/// Removes the mark set by `mark_uncommitted()` once the datatype `handle` was committed
+fn unmark_uncommitted(handle: MPI_Datatype) {
+    #[cfg(debug_assertions)]
+    {
+        if is_marked_uncommitted(handle) {
+            unsafe {
+                ffi::MPI_Type_delete_attr(handle, uncommitted_keyval());
+            }
+        }
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = handle;
+}
+
+/// Whether the datatype `handle` was marked by `mark_uncommitted()`
+#[cfg(debug_assertions)]
+fn is_marked_uncommitted(handle: MPI_Datatype) -> bool {
+    let mut value: *mut c_void = ptr::null_mut();
+    let value: *mut *mut c_void = &mut value;
+    let (_, flag) = unsafe {
+        with_uninitialized(|flag| {
+            ffi::MPI_Type_get_attr(handle, uncommitted_keyval(), value.cast(), flag)
+        })
+    };
+    flag != 0
+}
 
-use crate::with_uninitialized;
+/// Panics in debug builds if `handle` belongs to an uncommitted datatype
+///
+/// This costs one `MPI_Type_get_attr()`, a lookup local to the process, and is only done by the
+/// public `from_raw()` constructors. Handles known to be committed, e.g. of predefined or cached
+/// datatypes, are wrapped via `DatatypeRef::from_raw_unchecked()` instead.
+fn assert_committed(handle: MPI_Datatype) {
+    #[cfg(debug_assertions)]
+    {
+        if is_marked_uncommitted(handle) {
+            panic!(
+                "Datatype {:?} is not committed and must not be used for communication. Call \
+                 `UncommittedUserDatatype::commit()` instead of wrapping its handle via \
+                 `from_raw()`.",
+                handle
+            );
+        }
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = handle;
+}
 
 /// Datatype traits
 pub mod traits {
     pub use super::{
-        AsDatatype, Buffer, BufferMut, Collection, Datatype, Equivalence, Partitioned,
-        PartitionedBuffer, PartitionedBufferMut, Pointer, PointerMut, UncommittedDatatype,
+        AsByteBuffer, AsDatatype, AsIntegersMut, Buffer, BufferMut, Collection, Datatype,
+        Equivalence, Partitioned, PartitionedBuffer, PartitionedBufferMut, Pointer, PointerMut,
+        UncommittedDatatype,
     };
 }
 
@@ -105,8 +254,10 @@ unsafe impl<'a> AsRaw for DatatypeRef<'a> {
     }
 }
 
-impl<'a> FromRaw for DatatypeRef<'a> {
-    unsafe fn from_raw(datatype: MPI_Datatype) -> Self {
+impl<'a> DatatypeRef<'a> {
+    /// Like `from_raw()`, but without checking whether the datatype is committed in debug builds,
+    /// for handles that are known to be committed.
+    pub(crate) unsafe fn from_raw_unchecked(datatype: MPI_Datatype) -> Self {
         Self {
             datatype,
             phantom: PhantomData,
@@ -114,6 +265,13 @@ impl<'a> FromRaw for DatatypeRef<'a> {
     }
 }
 
+impl<'a> FromRaw for DatatypeRef<'a> {
+    unsafe fn from_raw(datatype: MPI_Datatype) -> Self {
+        assert_committed(datatype);
+        Self::from_raw_unchecked(datatype)
+    }
+}
+
 unsafe impl<'a> MatchesRaw for DatatypeRef<'a> {}
 
 impl<'a> Datatype for DatatypeRef<'a> {}
@@ -152,6 +310,21 @@ impl<'a> FromRaw for UncommittedDatatypeRef<'a> {
     }
 }
 
+impl UncommittedDatatypeRef<'static> {
+    /// The null datatype, `MPI_DATATYPE_NULL`, e.g. as a placeholder for a datatype that is not
+    /// known yet.
+    ///
+    /// It is not a `Datatype`, so it is rejected at compile time wherever a datatype is needed to
+    /// communicate.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    pub fn null() -> Self {
+        let null = unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL);
+        unsafe { UncommittedDatatypeRef::from_raw(null) }
+    }
+}
+
 unsafe impl<'a> MatchesRaw for UncommittedDatatypeRef<'a> {}
 
 impl<'a> UncommittedDatatype for UncommittedDatatypeRef<'a> {
@@ -167,6 +340,13 @@ pub type SystemDatatype = DatatypeRef<'static>;
 
 /// A direct equivalence exists between the implementing type and an MPI datatype
 ///
+/// The C types of `std::os::raw` and the `libc` crate, e.g. `c_int`, `c_long` or `c_char`, are
+/// aliases of the Rust primitives of the same size on each platform, so they are covered by the
+/// implementations for those and map to an MPI datatype of the correct size without any casts.
+///
+/// # Examples
+/// See `examples/c_types.rs`
+///
 /// # Standard section(s)
 ///
 /// 3.2.2
@@ -177,14 +357,30 @@ pub unsafe trait Equivalence {
     fn equivalent_datatype() -> Self::Out;
 }
 
+/// Every byte of every value of the implementing type is initialized
+///
+/// This holds for the Rust primitives but not for structs with padding between or after their
+/// fields, e.g. those deriving `Equivalence`, whose datatypes skip the padding. Only types
+/// implementing this can be viewed as raw bytes via `AsByteBuffer`, as reading their padding
+/// would read uninitialized memory.
+///
+/// # Safety
+///
+/// The type must not contain any padding or otherwise uninitialized bytes and must not permit
+/// mutation through a shared reference, e.g. via atomics or `Cell`.
+pub unsafe trait NoPadding: Equivalence {}
+
 macro_rules! equivalent_system_datatype {
-    ($rstype:path, $mpitype:path) => {
+    ($(#[$attr:meta])* $rstype:path, $mpitype:path) => {
+        $(#[$attr])*
         unsafe impl Equivalence for $rstype {
             type Out = SystemDatatype;
             fn equivalent_datatype() -> Self::Out {
-                unsafe { DatatypeRef::from_raw($mpitype) }
+                unsafe { DatatypeRef::from_raw_unchecked($mpitype) }
             }
         }
+
+        unsafe impl NoPadding for $rstype {}
     };
 }
 
@@ -213,6 +409,395 @@ equivalent_system_datatype!(usize, ffi::RSMPI_UINT64_T);
 #[cfg(target_pointer_width = "64")]
 equivalent_system_datatype!(isize, ffi::RSMPI_INT64_T);
 
+/// `Address` has the representation of `MPI_Aint` and is communicated as that integer.
+unsafe impl Equivalence for Address {
+    type Out = <MPI_Aint as Equivalence>::Out;
+    fn equivalent_datatype() -> Self::Out {
+        MPI_Aint::equivalent_datatype()
+    }
+}
+
+unsafe impl NoPadding for Address {}
+
+/// Exclusive access to a slice of atomic integers as the plain integers they are made of
+///
+/// Atomic integers are not `Equivalence`, since MPI reads and writes the bytes of a buffer
+/// without atomic operations, which would race with other threads accessing them through a shared
+/// reference. Borrowing the slice mutably rules that out, after which the atomics can be sent and
+/// received as the integers they have the same in-memory representation as.
+///
+/// ```compile_fail
+/// # use mpi::traits::*;
+/// # use std::sync::atomic::AtomicI32;
+/// # let universe = mpi::initialize().unwrap();
+/// # let world = universe.world();
+/// let counters = [AtomicI32::new(0), AtomicI32::new(1)];
+/// world.process_at_rank(0).send(&counters[..]);
+/// ```
+///
+/// # Examples
+/// See `examples/broadcast_atomic.rs`
+pub trait AsIntegersMut {
+    /// The integer type with the same in-memory representation
+    type Integer: Equivalence;
+
+    /// The atomics as plain integers, borrowed exclusively for as long as the result is used
+    fn as_integers_mut(&mut self) -> &mut [Self::Integer];
+}
+
+macro_rules! atomic_as_integers {
+    ($atomic:path, $integer:ty) => {
+        impl AsIntegersMut for [$atomic] {
+            type Integer = $integer;
+
+            fn as_integers_mut(&mut self) -> &mut [$integer] {
+                // The atomic has the same size and bit validity as the integer and the mutable
+                // borrow excludes any concurrent atomic access for its lifetime.
+                unsafe { slice::from_raw_parts_mut(self.as_mut_ptr() as *mut $integer, self.len()) }
+            }
+        }
+    };
+}
+
+atomic_as_integers!(AtomicI32, i32);
+atomic_as_integers!(AtomicU32, u32);
+#[cfg(target_has_atomic = "64")]
+atomic_as_integers!(AtomicI64, i64);
+#[cfg(target_has_atomic = "64")]
+atomic_as_integers!(AtomicU64, u64);
+
+macro_rules! system_datatypes {
+    ($($rstype:ident),*) => (
+        vec![$((stringify!($rstype), <$rstype as Equivalence>::equivalent_datatype())),*]
+    )
+}
+
+/// All Rust types with an equivalent `SystemDatatype`, by name, e.g. `("f64", MPI_DOUBLE)`.
+///
+/// Types with the same representation share a datatype, e.g. `usize` is `MPI_UINT64_T` on 64-bit
+/// platforms, just like `u64`.
+///
+/// # Examples
+/// See `examples/system_datatypes.rs`
+///
+/// # Standard section(s)
+///
+/// 3.2.2
+pub fn supported_system_datatypes() -> Vec<(&'static str, SystemDatatype)> {
+    system_datatypes![bool, f32, f64, i8, i16, i32, i64, u8, u16, u32, u64, isize, usize]
+}
+
+macro_rules! equivalent_half_datatype {
+    ($rstype:path) => {
+        /// There is no predefined MPI datatype for 16-bit floating point numbers, so they are
+        /// communicated as two bytes each.
+        ///
+        /// This suffices for transferring them, but MPI cannot compute with them, so reductions
+        /// have to use a `UserOperation` which converts to and from a wider type.
+        #[cfg(feature = "half")]
+        unsafe impl Equivalence for $rstype {
+            type Out = DatatypeRef<'static>;
+            fn equivalent_datatype() -> Self::Out {
+                cached_datatype::<Self, _>(|| UserDatatype::contiguous(2, &byte_datatype()))
+            }
+        }
+
+        #[cfg(feature = "half")]
+        unsafe impl NoPadding for $rstype {}
+    };
+}
+
+equivalent_half_datatype!(half::f16);
+equivalent_half_datatype!(half::bf16);
+
+macro_rules! system_datatype_constructors {
+    ($($ctor:ident),*) => (
+        $(pub fn $ctor() -> SystemDatatype {
+            //! The system datatype equivalent to the Rust primitive of the same name
+            <$ctor as Equivalence>::equivalent_datatype()
+        })*
+    )
+}
+
+/// The system datatypes equivalent to Rust primitives can be named directly, e.g.
+/// `SystemDatatype::f64()` is `MPI_DOUBLE`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use mpi::datatype::{SystemDatatype, UserDatatype};
+///
+/// let universe = mpi::initialize().unwrap();
+/// let triple = UserDatatype::contiguous(3, &SystemDatatype::f64());
+/// ```
+impl SystemDatatype {
+    system_datatype_constructors! {
+        bool, f32, f64, i8, i16, i32, i64, u8, u16, u32, u64, isize, usize
+    }
+
+    /// The datatype matching a Fortran `REAL` of kind `SELECTED_REAL_KIND(precision, range)`.
+    ///
+    /// At least one of the decimal `precision` and the decimal exponent `range` must be given.
+    ///
+    /// # Examples
+    /// See `examples/f90_datatypes.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 17.1.9
+    pub fn f90_real(precision: Option<c_int>, range: Option<c_int>) -> SystemDatatype {
+        assert!(
+            precision.is_some() || range.is_some(),
+            "At least one of 'precision' and 'range' must be given"
+        );
+        let undefined = unsafe_extern_static!(ffi::RSMPI_UNDEFINED);
+        unsafe {
+            DatatypeRef::from_raw_unchecked(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_f90_real(
+                        precision.unwrap_or(undefined),
+                        range.unwrap_or(undefined),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// The datatype matching a Fortran `COMPLEX` of kind `SELECTED_REAL_KIND(precision, range)`.
+    ///
+    /// At least one of the decimal `precision` and the decimal exponent `range` must be given.
+    ///
+    /// # Examples
+    /// See `examples/f90_datatypes.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 17.1.9
+    pub fn f90_complex(precision: Option<c_int>, range: Option<c_int>) -> SystemDatatype {
+        assert!(
+            precision.is_some() || range.is_some(),
+            "At least one of 'precision' and 'range' must be given"
+        );
+        let undefined = unsafe_extern_static!(ffi::RSMPI_UNDEFINED);
+        unsafe {
+            DatatypeRef::from_raw_unchecked(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_f90_complex(
+                        precision.unwrap_or(undefined),
+                        range.unwrap_or(undefined),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// The datatype matching a Fortran `INTEGER` of kind `SELECTED_INT_KIND(range)`.
+    ///
+    /// # Examples
+    /// See `examples/f90_datatypes.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 17.1.9
+    pub fn f90_integer(range: c_int) -> SystemDatatype {
+        unsafe {
+            DatatypeRef::from_raw_unchecked(
+                with_uninitialized(|newtype| ffi::MPI_Type_create_f90_integer(range, newtype)).1,
+            )
+        }
+    }
+
+    /// Duplicates this predefined datatype into a derived datatype that is owned and freed like
+    /// any other `UserDatatype`, e.g. for libraries that free the datatypes they are handed.
+    ///
+    /// This is `dup()` under a name that spells out the conversion.
+    ///
+    /// # Examples
+    /// See `examples/datatype_dup.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.10
+    pub fn to_user(&self) -> UserDatatype {
+        self.dup()
+    }
+
+    /// Derives a datatype identical to this predefined datatype but with its lower bound set to
+    /// `lower_bound` and its extent set to `extent`, e.g. to scatter a column of a row-major
+    /// matrix one element per process.
+    ///
+    /// This is `UserDatatype::resized()` applied to this datatype. The predefined datatype itself
+    /// is not affected.
+    ///
+    /// # Examples
+    /// See `examples/dup_resized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn dup_resized(&self, lower_bound: Address, extent: Address) -> UserDatatype {
+        UserDatatype::resized(self, lower_bound, extent)
+    }
+}
+
+/// Asserts that the extent of the MPI datatype equivalent to a type matches the size of the type.
+///
+/// A mismatch usually means that a manual `Equivalence` implementation has drifted from the
+/// layout of the type, e.g. after a field was added. This is intended to be used in the test
+/// suites of downstream crates and is only available with the `testing` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[macro_use] extern crate mpi;
+/// # fn main() {
+/// let universe = mpi::initialize().unwrap();
+/// assert_datatype_size!(f64);
+/// # }
+/// ```
+///
+/// See also `examples/datatype_size.rs`
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! assert_datatype_size {
+    ($t:ty) => {
+        assert_eq!(
+            $crate::datatype::UncommittedDatatype::extent(
+                &<$t as $crate::datatype::Equivalence>::equivalent_datatype()
+            ),
+            $crate::Address(::std::mem::size_of::<$t>() as $crate::ffi::MPI_Aint),
+            "the extent of the MPI datatype equivalent to `{}` does not match its size",
+            stringify!($t)
+        )
+    };
+}
+
+/// Checks that `datatype` describes every byte of `value` by packing `value` with it and
+/// unpacking the result into a zeroed copy.
+///
+/// Returns `true` if the copy is byte for byte equal to `value`. A datatype that skips a field,
+/// e.g. a `vector()` with too large a stride, leaves the bytes of that field zeroed and so fails
+/// the check, unless they happen to be zero in `value` as well. A datatype that reaches outside
+/// of `value` fails without any memory being accessed. So does a datatype that MPI fails to pack
+/// or unpack, if the error handler `MPI_ERRORS_RETURN` has been set.
+///
+/// This is intended to be used in the test suites of downstream crates to validate hand-built
+/// datatypes and is only available with the `testing` feature.
+///
+/// # Examples
+/// See `examples/pack_roundtrip.rs`
+///
+/// # Safety
+///
+/// All bytes of `value` are read as `u8`, so `T` must not contain any padding or otherwise
+/// uninitialized bytes, e.g. it must not be `(u8, u32)` or a struct with fields of different
+/// alignments, unless it is `#[repr(C, packed)]`.
+///
+/// # Standard section(s)
+///
+/// 4.2
+#[cfg(feature = "testing")]
+pub unsafe fn datatype_pack_roundtrip<T, D, C>(value: &T, datatype: &D, comm: &C) -> bool
+where
+    T: ?Sized,
+    D: Datatype,
+    C: ?Sized + Communicator,
+{
+    let size = size_of_val(value);
+    let start = datatype.true_lower_bound();
+    let end = start + datatype.true_extent();
+    if start < Address(0) || usize::try_from(end).map_or(true, |end| end > size) {
+        return false;
+    }
+
+    let value: *const T = value;
+    let value = value as *const u8;
+    let mut packed = vec![
+        0u8;
+        comm.pack_size(1, datatype)
+            .value_as()
+            .expect("MPI_Pack_size returned a negative buffer size!")
+    ];
+    let mut copy = vec![0u8; size];
+    let success: c_int = ffi::MPI_SUCCESS
+        .value_as()
+        .expect("MPI_SUCCESS does not fit into c_int");
+    let mut position = 0;
+    let code = ffi::MPI_Pack(
+        value as *const c_void,
+        1,
+        datatype.as_raw(),
+        packed.as_mut_ptr() as *mut c_void,
+        packed.count(),
+        &mut position,
+        comm.as_raw(),
+    );
+    if code != success {
+        return false;
+    }
+    let packed_len = position;
+    position = 0;
+    let code = ffi::MPI_Unpack(
+        packed.as_ptr() as *const c_void,
+        packed_len,
+        &mut position,
+        copy.as_mut_ptr() as *mut c_void,
+        1,
+        datatype.as_raw(),
+        comm.as_raw(),
+    );
+    code == success && slice::from_raw_parts(value, size) == &copy[..]
+}
+
+/// Constructs the `UserDatatype` of a `#[repr(C)]` struct from the names of its fields.
+///
+/// The displacement of each field is computed via `memoffset::offset_of!` and its datatype is
+/// that of the `Equivalence` of the field's type, then all of them are passed to
+/// `UserDatatype::structured()`. Fields which are not listed are not part of the datatype. This
+/// is a lightweight alternative to `#[derive(Equivalence)]` for one-off types.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[macro_use] extern crate mpi;
+/// # fn main() {
+/// #[repr(C)]
+/// struct Sample {
+///     id: i32,
+///     value: f64,
+/// }
+///
+/// let universe = mpi::initialize().unwrap();
+/// let datatype = struct_datatype!(Sample { id, value });
+/// # }
+/// ```
+///
+/// See also `examples/struct_datatype.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2
+#[macro_export]
+macro_rules! struct_datatype {
+    ($t:ty { $($field:ident),+ $(,)? }) => {{
+        let displacements = [$($crate::Address(
+            $crate::internal::memoffset::offset_of!($t, $field) as $crate::ffi::MPI_Aint,
+        )),+];
+        let types = [$($crate::datatype::UncommittedDatatypeRef::from(
+            $crate::datatype::internal::field_datatype(|s: &$t| &s.$field),
+        )),+];
+        $crate::datatype::UserDatatype::structured(
+            &vec![1; displacements.len()],
+            &displacements,
+            &types,
+        )
+    }};
+}
+
 /// A user defined MPI datatype
 ///
 /// # Standard section(s)
@@ -236,6 +821,21 @@ impl UserDatatype {
         UncommittedUserDatatype::contiguous(count, oldtype).commit()
     }
 
+    /// Like `contiguous()` but `count` may exceed the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/contiguous_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn contiguous_large<D>(count: LargeCount, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::contiguous_large(count, oldtype).commit()
+    }
+
     /// Construct a new datatype out of `count` blocks of `blocklength` elements of `oldtype`
     /// concatenated with the start of consecutive blocks placed `stride` elements apart.
     ///
@@ -252,225 +852,145 @@ impl UserDatatype {
         UncommittedUserDatatype::vector(count, blocklength, stride, oldtype).commit()
     }
 
-    /// Like `vector()` but `stride` is given in bytes rather than elements of `oldtype`.
+    /// Like `vector()` but checks that consecutive blocks do not overlap, i.e. that `stride` is
+    /// at least `blocklength` in magnitude, unless `allow_overlap` is set.
+    ///
+    /// Overlapping blocks are valid for sending but erroneous for receiving, and a stride shorter
+    /// than the block length is usually a mistake, e.g. when stride and block length are swapped.
+    ///
+    /// # Errors
+    ///
+    /// If the first two blocks overlap and `allow_overlap` is not set.
+    ///
+    /// # Examples
+    /// See `examples/vector.rs`
     ///
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn heterogeneous_vector<D>(
+    pub fn try_vector<D>(
         count: Count,
         blocklength: Count,
-        stride: Address,
+        stride: Count,
         oldtype: &D,
-    ) -> UserDatatype
+        allow_overlap: bool,
+    ) -> Result<UserDatatype, OverlapError>
     where
         D: UncommittedDatatype,
     {
-        UncommittedUserDatatype::heterogeneous_vector(count, blocklength, stride, oldtype).commit()
+        UncommittedUserDatatype::try_vector(count, blocklength, stride, oldtype, allow_overlap)
+            .map(UncommittedUserDatatype::commit)
     }
 
-    /// Constructs a new type out of multiple blocks of individual length and displacement.
-    /// Block `i` will be `blocklengths[i]` items of datytpe `oldtype` long and displaced by
-    /// `dispplacements[i]` items of the `oldtype`.
+    /// Construct a new datatype out of `count` elements of `oldtype`, taking every `k`th element
+    /// starting with the element at index `offset`.
+    ///
+    /// # Examples
+    /// See `examples/every_nth.rs`
     ///
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn indexed<D>(blocklengths: &[Count], displacements: &[Count], oldtype: &D) -> UserDatatype
+    pub fn every_nth<D>(k: Count, offset: Count, count: Count, oldtype: &D) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        UncommittedUserDatatype::indexed(blocklengths, displacements, oldtype).commit()
+        UncommittedUserDatatype::every_nth(k, offset, count, oldtype).commit()
     }
 
-    /// Constructs a new type out of multiple blocks of individual length and displacement.
-    /// Block `i` will be `blocklengths[i]` items of datytpe `oldtype` long and displaced by
-    /// `dispplacements[i]` bytes.
+    /// Construct a new datatype describing the slab of all elements `(i, j, k)` with a fixed `k`
+    /// of a three-dimensional `nx` by `ny` by `nz` array of `oldtype` stored in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// If any dimension is negative, `k` does not lie within `0..nz` or the number of elements of
+    /// the slab, `nx * ny`, exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/slab.rs`
     ///
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn heterogeneous_indexed<D>(
-        blocklengths: &[Count],
-        displacements: &[Address],
-        oldtype: &D,
-    ) -> UserDatatype
+    pub fn slab_xy<D>(nx: Count, ny: Count, nz: Count, k: Count, oldtype: &D) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        UncommittedUserDatatype::heterogeneous_indexed(blocklengths, displacements, oldtype)
-            .commit()
+        UncommittedUserDatatype::slab_xy(nx, ny, nz, k, oldtype).commit()
     }
 
-    /// Construct a new type out of blocks of the same length and individual displacements.
+    /// Construct a new datatype describing the block of `subsizes` elements of `oldtype` that
+    /// starts at `starts` within a multi-dimensional array of `sizes` elements stored in `order`.
+    ///
+    /// The extent of the datatype is that of the whole array.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 4.1.2
-    pub fn indexed_block<D>(
-        blocklength: Count,
-        displacements: &[Count],
+    /// 4.1.3
+    pub fn subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
         oldtype: &D,
     ) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        UncommittedUserDatatype::indexed_block(blocklength, displacements, oldtype).commit()
+        UncommittedUserDatatype::subarray(sizes, subsizes, starts, order, oldtype).commit()
     }
 
-    /// Construct a new type out of blocks of the same length and individual displacements.
-    /// Displacements are in bytes.
-    ///
-    /// # Standard section(s)
+    /// Like `subarray()` but checks that the sub-block lies within the array.
     ///
-    /// 4.1.2
-    pub fn heterogeneous_indexed_block<D>(
-        blocklength: Count,
-        displacements: &[Address],
-        oldtype: &D,
-    ) -> UserDatatype
-    where
-        D: UncommittedDatatype,
-    {
-        UncommittedUserDatatype::heterogeneous_indexed_block(blocklength, displacements, oldtype)
-            .commit()
-    }
-
-    /// Constructs a new datatype out of blocks of different length, displacement and datatypes
+    /// # Errors
     ///
-    /// # Examples
-    /// See `examples/structured.rs`
+    /// If for any dimension the sub-block is empty, starts before the array or extends beyond it.
     ///
-    /// # Standard section(s)
+    /// # Panics
     ///
-    /// 4.1.2
-    pub fn structured<D>(
-        blocklengths: &[Count],
-        displacements: &[Address],
-        types: &[D],
-    ) -> UserDatatype
-    where
-        D: UncommittedDatatype + MatchesRaw<Raw = MPI_Datatype>,
-    {
-        UncommittedUserDatatype::structured(blocklengths, displacements, types).commit()
-    }
-
-    /// Creates a DatatypeRef from this datatype object.
-    pub fn as_ref(&self) -> DatatypeRef<'_> {
-        unsafe { DatatypeRef::from_raw(self.as_raw()) }
-    }
-}
-
-// TODO and NOTE: These impls are not 100% implemented, but reflect the larger reality that the
-// current API does not strongly enforce the threading requirements of MPI's `MPI_Init_thread`
-// function. In order to enforce uniformity across platforms, these traits are explicitly applied.
-// Due to the nature of MPI implementations, without this declaration some implementations of
-// MPI would otherwise be Send + Sync by default, and others would be !Sync by default.
-unsafe impl Send for UserDatatype {}
-unsafe impl Sync for UserDatatype {}
-
-impl Clone for UserDatatype {
-    fn clone(&self) -> Self {
-        self.dup()
-    }
-}
-
-impl Drop for UserDatatype {
-    fn drop(&mut self) {
-        unsafe {
-            ffi::MPI_Type_free(&mut self.0);
-        }
-        assert_eq!(self.0, unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL));
-    }
-}
-
-unsafe impl AsRaw for UserDatatype {
-    type Raw = MPI_Datatype;
-    fn as_raw(&self) -> Self::Raw {
-        self.0
-    }
-}
-
-impl FromRaw for UserDatatype {
-    unsafe fn from_raw(handle: MPI_Datatype) -> Self {
-        assert_ne!(handle, ffi::RSMPI_DATATYPE_NULL);
-        UserDatatype(handle)
-    }
-}
-
-unsafe impl MatchesRaw for UserDatatype {}
-
-impl Datatype for UserDatatype {}
-impl UncommittedDatatype for UserDatatype {
-    type DuplicatedDatatype = UserDatatype;
-}
-
-impl<'a> From<&'a UserDatatype> for DatatypeRef<'a> {
-    fn from(datatype: &'a UserDatatype) -> Self {
-        unsafe { DatatypeRef::from_raw(datatype.as_raw()) }
-    }
-}
-
-impl<'a> From<&'a UserDatatype> for UncommittedDatatypeRef<'a> {
-    fn from(datatype: &'a UserDatatype) -> Self {
-        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
-    }
-}
-
-/// Represents an MPI datatype that has not yet been committed. Can be used to build up more complex
-/// datatypes before committing.
-///
-/// UncommittedUserDatatype does not implement Datatype - it cannot be used as a datatype, and must be
-/// commited to retrieve a UserDatatype that implements Datatype.
-///
-/// # Standard section(s)
-/// 4.1.9
-pub struct UncommittedUserDatatype(MPI_Datatype);
-
-impl UncommittedUserDatatype {
-    /// Constructs a new datatype by concatenating `count` repetitions of `oldtype`
+    /// If `sizes`, `subsizes` and `starts` are not of the same length.
     ///
     /// # Examples
-    /// See `examples/contiguous.rs`
+    /// See `examples/subarray.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 4.1.2
-    pub fn contiguous<D>(count: Count, oldtype: &D) -> Self
+    /// 4.1.3
+    pub fn try_subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> Result<UserDatatype, SubarrayBoundsError>
     where
         D: UncommittedDatatype,
     {
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_contiguous(count, oldtype.as_raw(), newtype)
-                })
-                .1,
-            )
-        }
+        UncommittedUserDatatype::try_subarray(sizes, subsizes, starts, order, oldtype)
+            .map(UncommittedUserDatatype::commit)
     }
 
-    /// Construct a new datatype out of `count` blocks of `blocklength` elements of `oldtype`
-    /// concatenated with the start of consecutive blocks placed `stride` elements apart.
+    /// Like `subarray()` for an array stored in column-major order, as used by Fortran.
     ///
     /// # Examples
-    /// See `examples/vector.rs`
+    /// See `examples/subarray.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 4.1.2
-    pub fn vector<D>(count: Count, blocklength: Count, stride: Count, oldtype: &D) -> Self
+    /// 4.1.3
+    pub fn fortran_subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        oldtype: &D,
+    ) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_vector(count, blocklength, stride, oldtype.as_raw(), newtype)
-                })
-                .1,
-            )
-        }
+        UncommittedUserDatatype::fortran_subarray(sizes, subsizes, starts, oldtype).commit()
     }
 
     /// Like `vector()` but `stride` is given in bytes rather than elements of `oldtype`.
@@ -483,24 +1003,11 @@ impl UncommittedUserDatatype {
         blocklength: Count,
         stride: Address,
         oldtype: &D,
-    ) -> Self
+    ) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_create_hvector(
-                        count,
-                        blocklength,
-                        stride,
-                        oldtype.as_raw(),
-                        newtype,
-                    )
-                })
-                .1,
-            )
-        }
+        UncommittedUserDatatype::heterogeneous_vector(count, blocklength, stride, oldtype).commit()
     }
 
     /// Constructs a new type out of multiple blocks of individual length and displacement.
@@ -510,30 +1017,34 @@ impl UncommittedUserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn indexed<D>(blocklengths: &[Count], displacements: &[Count], oldtype: &D) -> Self
+    pub fn indexed<D>(blocklengths: &[Count], displacements: &[Count], oldtype: &D) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        assert_eq!(
-            blocklengths.len(),
-            displacements.len(),
-            "'blocklengths' and 'displacements' must be the same length"
-        );
+        UncommittedUserDatatype::indexed(blocklengths, displacements, oldtype).commit()
+    }
 
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_indexed(
-                        blocklengths.count(),
-                        blocklengths.as_ptr(),
-                        displacements.as_ptr(),
-                        oldtype.as_raw(),
-                        newtype,
-                    )
-                })
-                .1,
-            )
-        }
+    /// Like `indexed()` but merges blocks that directly follow each other in memory into a single
+    /// larger block first.
+    ///
+    /// Only blocks that are next to each other in `blocklengths` and `displacements` are merged,
+    /// so the displacements should be sorted to get the fewest blocks. Empty blocks are dropped.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn indexed_coalesced<D>(
+        blocklengths: &[Count],
+        displacements: &[Count],
+        oldtype: &D,
+    ) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::indexed_coalesced(blocklengths, displacements, oldtype).commit()
     }
 
     /// Constructs a new type out of multiple blocks of individual length and displacement.
@@ -547,29 +1058,12 @@ impl UncommittedUserDatatype {
         blocklengths: &[Count],
         displacements: &[Address],
         oldtype: &D,
-    ) -> Self
+    ) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        assert_eq!(
-            blocklengths.len(),
-            displacements.len(),
-            "'blocklengths' and 'displacements' must be the same length"
-        );
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_create_hindexed(
-                        blocklengths.count(),
-                        blocklengths.as_ptr(),
-                        displacements.as_ptr(),
-                        oldtype.as_raw(),
-                        newtype,
-                    )
-                })
-                .1,
-            )
-        }
+        UncommittedUserDatatype::heterogeneous_indexed(blocklengths, displacements, oldtype)
+            .commit()
     }
 
     /// Construct a new type out of blocks of the same length and individual displacements.
@@ -577,24 +1071,15 @@ impl UncommittedUserDatatype {
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn indexed_block<D>(blocklength: Count, displacements: &[Count], oldtype: &D) -> Self
+    pub fn indexed_block<D>(
+        blocklength: Count,
+        displacements: &[Count],
+        oldtype: &D,
+    ) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_create_indexed_block(
-                        displacements.count(),
-                        blocklength,
-                        displacements.as_ptr(),
-                        oldtype.as_raw(),
-                        newtype,
-                    )
-                })
-                .1,
-            )
-        }
+        UncommittedUserDatatype::indexed_block(blocklength, displacements, oldtype).commit()
     }
 
     /// Construct a new type out of blocks of the same length and individual displacements.
@@ -607,584 +1092,5598 @@ impl UncommittedUserDatatype {
         blocklength: Count,
         displacements: &[Address],
         oldtype: &D,
-    ) -> Self
+    ) -> UserDatatype
     where
         D: UncommittedDatatype,
     {
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_create_hindexed_block(
-                        displacements.count(),
-                        blocklength,
-                        displacements.as_ptr(),
-                        oldtype.as_raw(),
-                        newtype,
-                    )
-                })
-                .1,
-            )
-        }
+        UncommittedUserDatatype::heterogeneous_indexed_block(blocklength, displacements, oldtype)
+            .commit()
     }
 
     /// Constructs a new datatype out of blocks of different length, displacement and datatypes
     ///
+    /// The elements of `types` do not need to share a concrete type. Trait objects such as
+    /// `Box<dyn Datatype<DuplicatedDatatype = UserDatatype>>` can be used to mix `SystemDatatype`s
+    /// and `UserDatatype`s in a layout that is only known at runtime.
+    ///
     /// # Examples
-    /// See `examples/structured.rs`
+    /// See `examples/structured.rs` and `examples/structured_dyn.rs`
     ///
     /// # Standard section(s)
     ///
     /// 4.1.2
-    pub fn structured<D>(blocklengths: &[Count], displacements: &[Address], types: &[D]) -> Self
+    pub fn structured<D>(
+        blocklengths: &[Count],
+        displacements: &[Address],
+        types: &[D],
+    ) -> UserDatatype
     where
-        D: UncommittedDatatype + MatchesRaw<Raw = MPI_Datatype>,
+        D: UncommittedDatatype,
     {
-        assert_eq!(
-            blocklengths.len(),
-            displacements.len(),
-            "'displacements', 'blocklengths', and 'types' must be the same length"
-        );
-        assert_eq!(
-            blocklengths.len(),
-            types.len(),
+        UncommittedUserDatatype::structured(blocklengths, displacements, types).commit()
+    }
+
+    /// Constructs a new datatype out of runs of `count` consecutive elements of `datatype`, for
+    /// each pair `(datatype, count)` in `runs`, with each run directly following the previous one.
+    ///
+    /// The displacement of each run is the sum of the extents of the runs before it. No padding
+    /// is inserted, so alignment of the runs is the responsibility of the caller.
+    ///
+    /// # Examples
+    /// See `examples/runs.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn runs<D>(runs: &[(D, Count)]) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::runs(runs).commit()
+    }
+
+    /// Constructs a new datatype describing row `row` of a sparse matrix in compressed sparse row
+    /// (CSR) format, i.e. both its column `indices` and its `values`.
+    ///
+    /// Row `row` consists of the elements `row_offsets[row]..row_offsets[row + 1]` of `indices`
+    /// and `values`. The displacements are the absolute addresses of these elements, so the
+    /// datatype has to be used with a `BottomBuffer` and can only describe the row of these
+    /// particular slices.
+    ///
+    /// # Examples
+    /// See `examples/csr_row.rs`
+    ///
+    /// # Panics
+    ///
+    /// If `row` or the offsets of the row are out of bounds.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.5
+    pub fn csr_row(
+        row_offsets: &[Count],
+        indices: &[i32],
+        values: &[f64],
+        row: usize,
+    ) -> UserDatatype {
+        UncommittedUserDatatype::csr_row(row_offsets, indices, values, row).commit()
+    }
+
+    /// Constructs a new datatype describing a complex number stored as its real part immediately
+    /// followed by its imaginary part, both of type `T`.
+    ///
+    /// A slice of `[re, im, re, im, ...]` holds consecutive instances of this datatype.
+    ///
+    /// # Examples
+    /// See `examples/complex_layouts.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn interleaved_complex<T>() -> UserDatatype
+    where
+        T: Equivalence,
+    {
+        UncommittedUserDatatype::interleaved_complex::<T>().commit()
+    }
+
+    /// Constructs a new datatype describing `n` complex numbers stored with all `n` real parts
+    /// followed by all `n` imaginary parts, all of type `T`.
+    ///
+    /// The elements are visited in the order `re[0], im[0], re[1], im[1], ...`, so one instance of
+    /// this datatype matches `n` instances of `interleaved_complex::<T>()`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is negative or `2 * n` exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/complex_layouts.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn split_complex<T>(n: Count) -> UserDatatype
+    where
+        T: Equivalence,
+    {
+        UncommittedUserDatatype::split_complex::<T>(n).commit()
+    }
+
+    /// Constructs a new datatype describing the elements of a row-major `rows` x `cols` matrix of
+    /// `oldtype` in column-major order.
+    ///
+    /// Sending one instance of this datatype and receiving `rows * cols` contiguous elements of
+    /// `oldtype` transposes the matrix, e.g. to exchange data with Fortran codes.
+    ///
+    /// # Examples
+    /// See `examples/transpose.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn transpose_2d<D>(rows: Count, cols: Count, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::transpose_2d(rows, cols, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing field `field` of `count` records of `num_fields`
+    /// elements of `oldtype` stored as a struct of arrays, with the arrays of consecutive fields
+    /// placed `field_stride` elements apart.
+    ///
+    /// Receiving the values of field `field` of `count` interleaved records, i.e. of an array of
+    /// structs sent with `every_nth(num_fields, field, count, oldtype)`, into one instance of this
+    /// datatype places the value of record `i` at element `field * field_stride + i`, so no
+    /// separate pass is needed to deinterleave the records.
+    ///
+    /// # Panics
+    ///
+    /// If `field` does not lie within `0..num_fields` or the offset of its array,
+    /// `field * field_stride`, exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/deinterleave.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn deinterleave<D>(
+        num_fields: Count,
+        field: Count,
+        count: Count,
+        field_stride: Count,
+        oldtype: &D,
+    ) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::deinterleave(num_fields, field, count, field_stride, oldtype)
+            .commit()
+    }
+
+    /// Constructs a new datatype describing the diagonal of a row-major `n` x `n` matrix of
+    /// `oldtype`.
+    ///
+    /// # Examples
+    /// See `examples/diagonal.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn diagonal<D>(n: Count, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::diagonal(n, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the upper or lower triangle, including the diagonal,
+    /// of a row-major `n` x `n` matrix of `oldtype`.
+    ///
+    /// # Examples
+    /// See `examples/triangular.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn triangular<D>(n: Count, kind: Triangle, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::triangular(n, kind, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the upper or lower triangle, including the diagonal,
+    /// of an `n` x `n` matrix of `oldtype` stored in `order`, visiting its elements in the order
+    /// of packed storage.
+    ///
+    /// Packed storage keeps only the `n * (n + 1) / 2` elements of the triangle contiguously, one
+    /// row after the other for `Order::C` and one column after the other for `Order::Fortran`, as
+    /// LAPACK does. Sending a full matrix with this datatype into a buffer of that many elements
+    /// thus packs the triangle, and receiving a packed triangle into a full matrix with it
+    /// unpacks it.
+    ///
+    /// # Examples
+    /// See `examples/packed_triangular.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn packed_triangular<D>(n: Count, kind: Triangle, order: Order, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::packed_triangular(n, kind, order, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the cells of one `color` of a checkerboard pattern on
+    /// a row-major grid of `ny` rows of `nx` elements of `oldtype`, e.g. for red-black
+    /// Gauss-Seidel.
+    ///
+    /// # Examples
+    /// See `examples/checkerboard.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn checkerboard<D>(nx: Count, ny: Count, color: CellColor, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::checkerboard(nx, ny, color, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the `len` elements of `oldtype` starting at element
+    /// `offset` of a global sequence of `global_len` elements.
+    ///
+    /// The extent of the datatype is that of the whole global sequence, so that it can be used to
+    /// position the data of the calling process within e.g. a file shared by all processes.
+    ///
+    /// # Examples
+    /// See `examples/file_block.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn file_block<D>(global_len: Count, offset: Count, len: Count, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::file_block(global_len, offset, len, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing `interior` consecutive elements of `oldtype` out of
+    /// every `total`, e.g. to skip the ghost cells padding the rows of a stencil grid.
+    ///
+    /// Consecutive instances of the datatype start `total` elements apart, so a buffer starting
+    /// at the first interior element of a row and a count of the number of rows describe the
+    /// interior of the whole grid.
+    ///
+    /// # Examples
+    /// See `examples/padded.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn padded<D>(interior: Count, total: Count, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::padded(interior, total, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the elements of a global sequence of `global`
+    /// elements of `oldtype` that belong to process `rank` out of `nprocs` in a block-cyclic
+    /// distribution with blocks of `block` elements.
+    ///
+    /// Block `i` of the sequence belongs to process `i % nprocs`, the last block may be shorter.
+    /// The extent of the datatype is that of the whole global sequence.
+    ///
+    /// # Examples
+    /// See `examples/block_cyclic.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn block_cyclic<D>(
+        global: Count,
+        block: Count,
+        nprocs: Count,
+        rank: Count,
+        oldtype: &D,
+    ) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::block_cyclic(global, block, nprocs, rank, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing an n-dimensional array of `oldtype` with the extents
+    /// `shape` and the byte strides `strides`, as found e.g. in sliced or transposed array views.
+    ///
+    /// The last axis varies fastest. Strides may be negative, elements are then located at
+    /// negative displacements relative to the first element of the array.
+    ///
+    /// # Examples
+    /// See `examples/strided.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn strided<D>(shape: &[Count], strides: &[Address], oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::strided(shape, strides, oldtype).commit()
+    }
+
+    /// Like `strided()` but `strides` are given in elements of `oldtype` rather than bytes, like
+    /// the strides of e.g. `ndarray` arrays.
+    ///
+    /// # Examples
+    /// See `examples/strided.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn from_strides<D>(shape: &[Count], strides: &[Count], oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::from_strides(shape, strides, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the elements of `oldtype` in each of the `ranges`,
+    /// given as indices into a buffer of `oldtype`.
+    ///
+    /// The elements are visited range by range in the given order, so the ranges need not be
+    /// sorted. They may also overlap, which is fine for sending, but receiving into overlapping
+    /// elements is erroneous, see `validate_non_overlapping()`.
+    ///
+    /// # Examples
+    /// See `examples/from_ranges.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn from_ranges<D>(ranges: &[Range<Count>], oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::from_ranges(ranges, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing `len` consecutive elements of `oldtype` in a ring
+    /// buffer of `total` elements, starting at index `start` and wrapping around to the start of
+    /// the buffer if necessary.
+    ///
+    /// # Examples
+    /// See `examples/wraparound.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn wraparound<D>(total: Count, start: Count, len: Count, oldtype: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::wraparound(total, start, len, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing tiles of `tile` consecutive elements of `oldtype`
+    /// that cover a sequence of `total` elements, with consecutive tiles sharing `overlap`
+    /// elements, e.g. the input tiles of an overlap-add convolution.
+    ///
+    /// Overlapping blocks are only valid for sending: receiving into them would write the shared
+    /// elements more than once, which MPI forbids.
+    ///
+    /// # Examples
+    /// See `examples/overlapping_tiles.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn overlapping_tiles<D>(
+        total: Count,
+        tile: Count,
+        overlap: Count,
+        oldtype: &D,
+    ) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::overlapping_tiles(total, tile, overlap, oldtype).commit()
+    }
+
+    /// Constructs a new datatype describing the field of type `F` at byte `offset` within a
+    /// value of type `T`.
+    ///
+    /// The extent of the datatype is the size of `T`, so `n` instances of it pick the field out
+    /// of each of `n` consecutive values of type `T`, e.g. in combination with
+    /// `View::try_with_count_and_datatype()`, without repacking the field into a separate buffer.
+    ///
+    /// # Examples
+    /// See `examples/field_of.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn field_of<T, F>(offset: Address) -> UserDatatype
+    where
+        F: Equivalence,
+    {
+        UncommittedUserDatatype::field_of::<T, F>(offset).commit()
+    }
+
+    /// Constructs a new datatype describing this datatype directly followed by `next`.
+    ///
+    /// The lower bound of `next` is placed at the upper bound of this datatype, so chaining calls,
+    /// e.g. `a.then(&b).then(&c)`, lays out a record of its parts back to back without padding,
+    /// even if some of them have a nonzero lower bound, and its extent is the sum of theirs.
+    ///
+    /// # Examples
+    /// See `examples/then.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn then<D>(&self, next: &D) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::sequence(self, next).commit()
+    }
+
+    /// Rebuilds a datatype from a description of how it was constructed, e.g. one returned by
+    /// `to_descriptor()` on another process.
+    ///
+    /// # Errors
+    ///
+    /// If the descriptor names an unknown predefined datatype, uses a constructor that cannot be
+    /// rebuilt, or does not hold the arguments its constructor expects.
+    ///
+    /// # Examples
+    /// See `examples/layout_descriptor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.13
+    pub fn from_descriptor(descriptor: &LayoutDescriptor) -> Result<UserDatatype, DescriptorError> {
+        UncommittedUserDatatype::from_descriptor(descriptor).map(UncommittedUserDatatype::commit)
+    }
+
+    /// The byte ranges that `base_len` consecutive instances of this datatype access in a buffer,
+    /// relative to the start of the buffer.
+    ///
+    /// The ranges are given in the order in which MPI accesses the data, i.e. the order of the
+    /// type map, and adjacent ranges are merged. Gaps between the ranges are holes in the layout,
+    /// while ranges that intersect each other reveal overlapping accesses. This is meant for
+    /// debugging derived datatypes; it decodes the whole datatype and can be slow for large ones.
+    ///
+    /// # Panics
+    ///
+    /// If the datatype or one it was built from was constructed by a constructor that cannot be
+    /// decoded, e.g. `MPI_Type_create_darray()`, or if it accesses memory before the start of the
+    /// buffer.
+    ///
+    /// # Examples
+    /// See `examples/memory_map.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1, 4.1.13
+    pub fn memory_map(&self, base_len: usize) -> Vec<Range<usize>> {
+        let part = type_map(self).expect("The datatype cannot be decoded.");
+        let extent = self.extent();
+        (0..base_len)
+            .flat_map(|i| {
+                let displacement = extent
+                    * i.value_as::<MPI_Aint>()
+                        .expect("Element index cannot be expressed as an MPI Address.");
+                part.iter()
+                    .map(move |range| range.start + displacement..range.end + displacement)
+            })
+            .fold(Vec::new(), |mut map: Vec<Range<usize>>, range| {
+                let to_usize = |address: Address| -> usize {
+                    usize::try_from(address)
+                        .expect("The datatype accesses memory before the start of the buffer.")
+                };
+                let range = to_usize(range.start)..to_usize(range.end);
+                match map.last_mut() {
+                    Some(last) if last.end == range.start => last.end = range.end,
+                    _ => map.push(range),
+                }
+                map
+            })
+    }
+
+    /// Checks that no two blocks of this datatype occupy overlapping regions of memory.
+    ///
+    /// Receiving into a datatype with overlapping blocks silently corrupts data, so this can be
+    /// used to double check hand written displacements, e.g. those passed to `structured()`. A
+    /// block of several elements whose extent is smaller than their true extent overlaps itself.
+    /// Only datatypes constructed by `structured()` or any of the `indexed()` family are inspected,
+    /// other datatypes are always accepted.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.13
+    pub fn validate_non_overlapping(&self) -> Result<(), OverlapError> {
+        let contents = match self.contents() {
+            Some(contents) => contents,
+            None => return Ok(()),
+        };
+
+        match contents.combiner {
+            Combiner::Structured
+            | Combiner::Indexed
+            | Combiner::HeterogeneousIndexed
+            | Combiner::IndexedBlock
+            | Combiner::HeterogeneousIndexedBlock => {}
+            _ => return Ok(()),
+        }
+        let blocks = contents
+            .arguments()
+            .and_then(|arguments| arguments.blocks(&contents.datatypes))
+            .expect("Datatype arguments cannot be decoded.");
+
+        // The memory spanned by each non-empty block as a half-open byte range
+        let mut spans = Vec::new();
+        for (i, block) in blocks.iter().enumerate() {
+            let (blocklength, displacement) = (block.blocklength, block.displacement);
+            let datatype = &contents.datatypes[block.datatype];
+            let true_extent = datatype.true_extent();
+            if blocklength <= 0 || true_extent <= Address(0) {
+                continue;
+            }
+            let extent = datatype.extent();
+            if blocklength > 1 && extent.abs() < true_extent {
+                return Err(OverlapError {
+                    first: i,
+                    second: i,
+                });
+            }
+            let span = (blocklength - 1) * extent;
+            let start = displacement + datatype.true_lower_bound() + span.min(Address(0));
+            let end =
+                displacement + datatype.true_lower_bound() + true_extent + span.max(Address(0));
+            spans.push((start, end, i));
+        }
+        spans.sort();
+
+        // Sorted by start, each block has to start at or after the end of the previous one.
+        let mut previous: Option<(Address, usize)> = None;
+        for &(start, end, i) in &spans {
+            if let Some((previous_end, j)) = previous {
+                if start < previous_end {
+                    return Err(OverlapError {
+                        first: j,
+                        second: i,
+                    });
+                }
+            }
+            previous = Some((end, i));
+        }
+
+        Ok(())
+    }
+
+    /// Constructs a new datatype out of a runtime description of the fields of a record.
+    ///
+    /// Each `Field` becomes one block of the resulting struct datatype, see `structured()`.
+    ///
+    /// # Examples
+    /// See `examples/layout.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn from_layout(layout: &[Field]) -> UserDatatype {
+        let blocklengths: Vec<Count> = layout.iter().map(|field| field.count).collect();
+        let displacements: Vec<Address> = layout.iter().map(|field| field.offset).collect();
+        let types: Vec<SystemDatatype> = layout.iter().map(|field| field.base).collect();
+        UserDatatype::structured(&blocklengths, &displacements, &types)
+    }
+
+    /// Constructs a new struct datatype out of `(base, blocklength, displacement)` triples, e.g.
+    /// generated at runtime.
+    ///
+    /// Each triple becomes one block of `blocklength` elements of `base` at byte `displacement`,
+    /// see `structured()`.
+    ///
+    /// # Examples
+    /// See `examples/layout.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn from_fields<I>(fields: I) -> UserDatatype
+    where
+        I: IntoIterator<Item = (SystemDatatype, Count, Address)>,
+    {
+        let mut types = Vec::new();
+        let mut blocklengths = Vec::new();
+        let mut displacements = Vec::new();
+        for (base, blocklength, displacement) in fields {
+            types.push(base);
+            blocklengths.push(blocklength);
+            displacements.push(displacement);
+        }
+        UserDatatype::structured(&blocklengths, &displacements, &types)
+    }
+
+    /// Constructs a new datatype of `layout.size()` contiguous `MPI_BYTE`s describing an opaque
+    /// allocation of that layout.
+    ///
+    /// This is a low-level escape hatch, e.g. for allocator-aware code that has to transfer blocks
+    /// of memory it knows nothing about but their layout. `layout.align()` is not part of the
+    /// datatype, so the receive buffer has to be suitably aligned by other means.
+    ///
+    /// The bytes are transferred as they are, without any conversion, so the resulting datatype is
+    /// not portable: whatever they contain has to be interpreted in the same way, e.g. with the
+    /// same byte order and padding, by all processes involved, see `is_portable()`.
+    ///
+    /// # Examples
+    /// See `examples/layout_bytes.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.3.1, 4.1.2
+    pub fn from_layout_bytes(layout: alloc::Layout) -> UserDatatype {
+        let size = layout
+            .size()
+            .value_as()
+            .expect("Size of the layout cannot be expressed as an MPI Count.");
+        UserDatatype::contiguous_large(size, &byte_datatype())
+    }
+
+    /// Constructs a new datatype identical to `oldtype` but with its lower bound set to
+    /// `lower_bound` and its extent set to `extent`.
+    ///
+    /// `oldtype` may be a predefined datatype, e.g. `SystemDatatype::f64()`, to change the stride
+    /// at which consecutive elements of it are taken from a buffer. The predefined datatype itself
+    /// is not affected, neither by constructing nor by freeing the resized one.
+    ///
+    /// An `extent` of zero is valid and yields a datatype whose elements all start at the same
+    /// address, e.g. a marker that occupies no space in a layout of its own. Its `size()` is still
+    /// that of `oldtype`. Consequently, `count > 1` elements of such a datatype, e.g. in
+    /// `contiguous()` or a buffer, refer to the same memory over and over: sending it repeats the
+    /// same data and receiving into it is erroneous, as MPI forbids receive buffers that overlap
+    /// themselves.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`, `examples/resized_scatter.rs` and `examples/zero_extent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn resized<D>(oldtype: &D, lower_bound: Address, extent: Address) -> UserDatatype
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::resized(oldtype, lower_bound, extent).commit()
+    }
+
+    /// Constructs a copy of this datatype with its lower bound set to `lower_bound` and its extent
+    /// set to `extent`.
+    ///
+    /// MPI datatypes cannot be modified after construction, so this always yields a new datatype
+    /// and leaves `self` untouched. It is shorthand for `UserDatatype::resized(self, lower_bound,
+    /// extent)`.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn with_extent(&self, lower_bound: Address, extent: Address) -> UserDatatype {
+        UserDatatype::resized(self, lower_bound, extent)
+    }
+
+    /// Creates a DatatypeRef from this datatype object.
+    pub fn as_ref(&self) -> DatatypeRef<'_> {
+        unsafe { DatatypeRef::from_raw_unchecked(self.as_raw()) }
+    }
+}
+
+// TODO and NOTE: These impls are not 100% implemented, but reflect the larger reality that the
+// current API does not strongly enforce the threading requirements of MPI's `MPI_Init_thread`
+// function. In order to enforce uniformity across platforms, these traits are explicitly applied.
+// Due to the nature of MPI implementations, without this declaration some implementations of
+// MPI would otherwise be Send + Sync by default, and others would be !Sync by default.
+unsafe impl Send for UserDatatype {}
+unsafe impl Sync for UserDatatype {}
+
+impl Clone for UserDatatype {
+    fn clone(&self) -> Self {
+        self.dup()
+    }
+}
+
+impl Drop for UserDatatype {
+    fn drop(&mut self) {
+        trace_datatype!("freeing", self);
+        unsafe {
+            ffi::MPI_Type_free(&mut self.0);
+        }
+        assert_eq!(self.0, unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL));
+    }
+}
+
+unsafe impl AsRaw for UserDatatype {
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl FromRaw for UserDatatype {
+    unsafe fn from_raw(handle: MPI_Datatype) -> Self {
+        assert_ne!(handle, ffi::RSMPI_DATATYPE_NULL);
+        assert_committed(handle);
+        let datatype = UserDatatype(handle);
+        trace_datatype!("created", datatype);
+        datatype
+    }
+}
+
+unsafe impl MatchesRaw for UserDatatype {}
+
+/// Formats the constructor of the datatype, or with `{:#?}` the whole tree of constructors as
+/// described by `fmt_tree()`.
+impl fmt::Debug for UserDatatype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            self.fmt_tree(f, 0)
+        } else {
+            f.debug_tuple("UserDatatype")
+                .field(&self.envelope().combiner)
+                .finish()
+        }
+    }
+}
+
+impl Datatype for UserDatatype {}
+impl UncommittedDatatype for UserDatatype {
+    type DuplicatedDatatype = UserDatatype;
+}
+
+impl<'a> From<&'a UserDatatype> for DatatypeRef<'a> {
+    fn from(datatype: &'a UserDatatype) -> Self {
+        unsafe { DatatypeRef::from_raw_unchecked(datatype.as_raw()) }
+    }
+}
+
+impl<'a> From<&'a UserDatatype> for UncommittedDatatypeRef<'a> {
+    fn from(datatype: &'a UserDatatype) -> Self {
+        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
+    }
+}
+
+/// A committed datatype with shared ownership
+///
+/// Cloning a `SharedDatatype` only increments a reference count instead of duplicating the
+/// underlying `MPI_Datatype` like cloning a `UserDatatype` does. The `MPI_Datatype` is freed once
+/// the last clone is dropped.
+///
+/// # Examples
+/// See `examples/shared_datatype_threads.rs`
+#[derive(Clone, Debug)]
+pub struct SharedDatatype(Arc<UserDatatype>);
+
+impl SharedDatatype {
+    /// Shares ownership of `datatype`.
+    pub fn new(datatype: UserDatatype) -> Self {
+        SharedDatatype(Arc::new(datatype))
+    }
+
+    /// Creates a DatatypeRef from this datatype object.
+    pub fn as_ref(&self) -> DatatypeRef<'_> {
+        unsafe { DatatypeRef::from_raw_unchecked(self.as_raw()) }
+    }
+}
+
+impl From<UserDatatype> for SharedDatatype {
+    fn from(datatype: UserDatatype) -> Self {
+        SharedDatatype::new(datatype)
+    }
+}
+
+unsafe impl AsRaw for SharedDatatype {
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        self.0.as_raw()
+    }
+}
+
+impl Datatype for SharedDatatype {}
+impl UncommittedDatatype for SharedDatatype {
+    type DuplicatedDatatype = UserDatatype;
+}
+
+impl<'a> From<&'a SharedDatatype> for DatatypeRef<'a> {
+    fn from(datatype: &'a SharedDatatype) -> Self {
+        unsafe { DatatypeRef::from_raw_unchecked(datatype.as_raw()) }
+    }
+}
+
+impl<'a> From<&'a SharedDatatype> for UncommittedDatatypeRef<'a> {
+    fn from(datatype: &'a SharedDatatype) -> Self {
+        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
+    }
+}
+
+/// A datatype that is committed on first use in communication
+///
+/// It can be used as a building block of other datatypes like an `UncommittedUserDatatype`, which
+/// never commits it. Only `committed()` commits a duplicate of it, once, and caches the result, so
+/// that large hierarchies of datatypes only pay for committing the ones actually communicated.
+///
+/// `LazyView` and `LazyMutView` pass it to communication directly, calling `committed()` when
+/// the operation asks for their datatype.
+///
+/// # Examples
+/// See `examples/lazy_datatype.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.9
+#[derive(Debug)]
+pub struct LazyDatatype {
+    uncommitted: UncommittedUserDatatype,
+    committed: unsync::OnceCell<UserDatatype>,
+}
+
+impl LazyDatatype {
+    /// Defers committing `datatype` until it is first used in communication.
+    pub fn new(datatype: UncommittedUserDatatype) -> Self {
+        LazyDatatype {
+            uncommitted: datatype,
+            committed: unsync::OnceCell::new(),
+        }
+    }
+
+    /// The committed datatype, committing it if this has not been done before.
+    pub fn committed(&self) -> &UserDatatype {
+        self.committed
+            .get_or_init(|| self.uncommitted.dup().commit())
+    }
+
+    /// Whether `committed()` has been called before.
+    pub fn is_committed(&self) -> bool {
+        self.committed.get().is_some()
+    }
+}
+
+impl From<UncommittedUserDatatype> for LazyDatatype {
+    fn from(datatype: UncommittedUserDatatype) -> Self {
+        LazyDatatype::new(datatype)
+    }
+}
+
+unsafe impl AsRaw for LazyDatatype {
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        self.uncommitted.as_raw()
+    }
+}
+
+impl UncommittedDatatype for LazyDatatype {
+    type DuplicatedDatatype = UncommittedUserDatatype;
+}
+
+impl<'a> From<&'a LazyDatatype> for UncommittedDatatypeRef<'a> {
+    fn from(datatype: &'a LazyDatatype) -> Self {
+        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
+    }
+}
+
+/// A committed datatype that is built from elements of `T`
+///
+/// The constructors only take the layout of the elements of `T` within the datatype, i.e. they
+/// combine `T::equivalent_datatype()` and nothing else. This makes it known at the type level that
+/// the datatype describes a selection of elements of a `[T]`, which `SafeView` relies on to view
+/// slices without `unsafe`. Datatypes combining different types are built as `UserDatatype`s and
+/// viewed through `View`.
+///
+/// # Examples
+/// See `examples/safe_view.rs`
+#[derive(Debug)]
+pub struct TypedDatatype<T> {
+    datatype: UserDatatype,
+    phantom: PhantomData<T>,
+}
+
+impl<T> TypedDatatype<T>
+where
+    T: Equivalence,
+{
+    /// `count` consecutive elements of `T`, see `UserDatatype::contiguous()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn contiguous(count: Count) -> Self {
+        TypedDatatype::wrap(UserDatatype::contiguous(count, &T::equivalent_datatype()))
+    }
+
+    /// `count` blocks of `blocklength` elements of `T` placed `stride` elements apart, see
+    /// `UserDatatype::vector()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn vector(count: Count, blocklength: Count, stride: Count) -> Self {
+        TypedDatatype::wrap(UserDatatype::vector(
+            count,
+            blocklength,
+            stride,
+            &T::equivalent_datatype(),
+        ))
+    }
+
+    /// Blocks of `blocklength` elements of `T` starting at the element indices `displacements`,
+    /// see `UserDatatype::indexed_block()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn indexed_block(blocklength: Count, displacements: &[Count]) -> Self {
+        TypedDatatype::wrap(UserDatatype::indexed_block(
+            blocklength,
+            displacements,
+            &T::equivalent_datatype(),
+        ))
+    }
+
+    /// A sub-block of a multidimensional array of elements of `T`, see `UserDatatype::subarray()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn subarray(sizes: &[Count], subsizes: &[Count], starts: &[Count], order: Order) -> Self {
+        TypedDatatype::wrap(UserDatatype::subarray(
+            sizes,
+            subsizes,
+            starts,
+            order,
+            &T::equivalent_datatype(),
+        ))
+    }
+
+    fn wrap(datatype: UserDatatype) -> Self {
+        TypedDatatype {
+            datatype,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> TypedDatatype<T> {
+    /// The untyped datatype, e.g. to build other datatypes from it
+    pub fn datatype(&self) -> &UserDatatype {
+        &self.datatype
+    }
+
+    /// Forgets the element type, e.g. to combine the datatype with others
+    pub fn into_datatype(self) -> UserDatatype {
+        self.datatype
+    }
+}
+
+unsafe impl<T> AsRaw for TypedDatatype<T> {
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        self.datatype.as_raw()
+    }
+}
+
+impl<T> Datatype for TypedDatatype<T> {}
+impl<T> UncommittedDatatype for TypedDatatype<T> {
+    type DuplicatedDatatype = UserDatatype;
+}
+
+impl<'a, T> From<&'a TypedDatatype<T>> for DatatypeRef<'a> {
+    fn from(datatype: &'a TypedDatatype<T>) -> Self {
+        unsafe { DatatypeRef::from_raw_unchecked(datatype.as_raw()) }
+    }
+}
+
+impl<'a, T> From<&'a TypedDatatype<T>> for UncommittedDatatypeRef<'a> {
+    fn from(datatype: &'a TypedDatatype<T>) -> Self {
+        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
+    }
+}
+
+/// Represents an MPI datatype that has not yet been committed. Can be used to build up more complex
+/// datatypes before committing.
+///
+/// UncommittedUserDatatype does not implement Datatype - it cannot be used as a datatype, and must be
+/// commited to retrieve a UserDatatype that implements Datatype.
+///
+/// # Standard section(s)
+/// 4.1.9
+pub struct UncommittedUserDatatype(MPI_Datatype);
+
+impl UncommittedUserDatatype {
+    /// Constructs a new datatype by concatenating `count` repetitions of `oldtype`
+    ///
+    /// # Examples
+    /// See `examples/contiguous.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn contiguous<D>(count: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_contiguous(count, oldtype.as_raw(), newtype)
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Like `contiguous()` but `count` may exceed the range of `Count`.
+    ///
+    /// Large counts are split into chunks of `Count::MAX` repetitions of `oldtype`, followed by
+    /// the remaining repetitions, as `MPI_Type_contiguous_c()` is only available from MPI 4.0.
+    ///
+    /// # Examples
+    /// See `examples/contiguous_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn contiguous_large<D>(count: LargeCount, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(count >= 0, "'count' must not be negative");
+
+        if let Ok(count) = count.value_as() {
+            return UncommittedUserDatatype::contiguous(count, oldtype);
+        }
+
+        let chunk = LargeCount::from(Count::MAX);
+        let chunks = UncommittedUserDatatype::contiguous(
+            (count / chunk)
+                .value_as()
+                .expect("Number of chunks cannot be expressed as an MPI Count."),
+            &UncommittedUserDatatype::contiguous(Count::MAX, oldtype),
+        );
+        let remainder = UncommittedUserDatatype::contiguous(
+            (count % chunk)
+                .value_as()
+                .expect("Remainder of chunks cannot be expressed as an MPI Count."),
+            oldtype,
+        );
+        let remainder_displacement = chunks.extent();
+
+        UncommittedUserDatatype::structured(
+            &[1, 1],
+            &[Address(0), remainder_displacement],
+            &[chunks, remainder],
+        )
+    }
+
+    /// Construct a new datatype out of `count` blocks of `blocklength` elements of `oldtype`
+    /// concatenated with the start of consecutive blocks placed `stride` elements apart.
+    ///
+    /// # Examples
+    /// See `examples/vector.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn vector<D>(count: Count, blocklength: Count, stride: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_vector(count, blocklength, stride, oldtype.as_raw(), newtype)
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Like `vector()` but checks that consecutive blocks do not overlap, unless `allow_overlap`
+    /// is set.
+    ///
+    /// # Errors
+    ///
+    /// If the first two blocks overlap and `allow_overlap` is not set.
+    ///
+    /// # Examples
+    /// See `examples/vector.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn try_vector<D>(
+        count: Count,
+        blocklength: Count,
+        stride: Count,
+        oldtype: &D,
+        allow_overlap: bool,
+    ) -> Result<Self, OverlapError>
+    where
+        D: UncommittedDatatype,
+    {
+        if !allow_overlap && count > 1 && blocklength > 0 && stride.abs() < blocklength {
+            return Err(OverlapError {
+                first: 0,
+                second: 1,
+            });
+        }
+        Ok(UncommittedUserDatatype::vector(
+            count,
+            blocklength,
+            stride,
+            oldtype,
+        ))
+    }
+
+    /// Construct a new datatype out of `count` elements of `oldtype`, taking every `k`th element
+    /// starting with the element at index `offset`.
+    ///
+    /// This is a vector of blocks of one element placed `k` elements apart, displaced by `offset`
+    /// elements, so that the lower bound of the datatype is the element at index `offset`.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is not positive or `offset` is negative.
+    ///
+    /// # Examples
+    /// See `examples/every_nth.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn every_nth<D>(k: Count, offset: Count, count: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(k > 0, "'k' must be positive");
+        assert!(offset >= 0, "'offset' must not be negative");
+
+        let selection = UncommittedUserDatatype::vector(count, 1, k, oldtype);
+        UncommittedUserDatatype::structured(&[1], &[offset * oldtype.extent()], &[selection])
+    }
+
+    /// Construct a new datatype describing the slab of all elements `(i, j, k)` with a fixed `k`
+    /// of a three-dimensional `nx` by `ny` by `nz` array of `oldtype` stored in row-major order.
+    ///
+    /// Element `(i, j, k)` is stored at index `(i * ny + j) * nz + k`, so the slab consists of
+    /// every `nz`th element starting at index `k`, see `every_nth()`. The elements are ordered by
+    /// `i`, then `j`.
+    ///
+    /// # Panics
+    ///
+    /// If any dimension is negative, `k` does not lie within `0..nz` or the number of elements of
+    /// the slab, `nx * ny`, exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/slab.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn slab_xy<D>(nx: Count, ny: Count, nz: Count, k: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            nx >= 0 && ny >= 0 && nz >= 0,
+            "the dimensions must not be negative"
+        );
+        assert!(0 <= k && k < nz, "'k' must lie within '0..nz'");
+        let count = nx
+            .checked_mul(ny)
+            .expect("The number of elements of the slab exceeds the range of Count");
+        UncommittedUserDatatype::every_nth(nz, k, count, oldtype)
+    }
+
+    /// Construct a new datatype describing the block of `subsizes` elements of `oldtype` that
+    /// starts at `starts` within a multi-dimensional array of `sizes` elements stored in `order`.
+    ///
+    /// # Panics
+    ///
+    /// If `sizes`, `subsizes` and `starts` are not of the same length.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            sizes.len() == subsizes.len() && sizes.len() == starts.len(),
+            "'sizes', 'subsizes', and 'starts' must be the same length"
+        );
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_subarray(
+                        sizes.count(),
+                        sizes.as_ptr(),
+                        subsizes.as_ptr(),
+                        starts.as_ptr(),
+                        order.as_raw(),
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Like `subarray()` but checks that the sub-block lies within the array.
+    ///
+    /// # Errors
+    ///
+    /// If for any dimension the sub-block is empty, starts before the array or extends beyond it.
+    ///
+    /// # Panics
+    ///
+    /// If `sizes`, `subsizes` and `starts` are not of the same length.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn try_subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        order: Order,
+        oldtype: &D,
+    ) -> Result<Self, SubarrayBoundsError>
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            sizes.len() == subsizes.len() && sizes.len() == starts.len(),
+            "'sizes', 'subsizes', and 'starts' must be the same length"
+        );
+        for (dim, ((&size, &subsize), &start)) in sizes.iter().zip(subsizes).zip(starts).enumerate()
+        {
+            if subsize < 1 || start < 0 || start > size - subsize {
+                return Err(SubarrayBoundsError {
+                    dim,
+                    size,
+                    subsize,
+                    start,
+                });
+            }
+        }
+        Ok(UncommittedUserDatatype::subarray(
+            sizes, subsizes, starts, order, oldtype,
+        ))
+    }
+
+    /// Like `subarray()` for an array stored in column-major order, as used by Fortran.
+    ///
+    /// # Examples
+    /// See `examples/subarray.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.3
+    pub fn fortran_subarray<D>(
+        sizes: &[Count],
+        subsizes: &[Count],
+        starts: &[Count],
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::subarray(sizes, subsizes, starts, Order::Fortran, oldtype)
+    }
+
+    /// Like `vector()` but `stride` is given in bytes rather than elements of `oldtype`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn heterogeneous_vector<D>(
+        count: Count,
+        blocklength: Count,
+        stride: Address,
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_hvector(
+                        count,
+                        blocklength,
+                        stride.0,
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Constructs a new type out of multiple blocks of individual length and displacement.
+    /// Block `i` will be `blocklengths[i]` items of datytpe `oldtype` long and displaced by
+    /// `dispplacements[i]` items of the `oldtype`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn indexed<D>(blocklengths: &[Count], displacements: &[Count], oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            blocklengths.len(),
+            displacements.len(),
+            "'blocklengths' and 'displacements' must be the same length"
+        );
+
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_indexed(
+                        blocklengths.count(),
+                        blocklengths.as_ptr(),
+                        displacements.as_ptr(),
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Like `indexed()` but merges blocks that directly follow each other in memory into a single
+    /// larger block first.
+    ///
+    /// Only blocks that are next to each other in `blocklengths` and `displacements` are merged,
+    /// so the displacements should be sorted to get the fewest blocks. Empty blocks are dropped.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn indexed_coalesced<D>(
+        blocklengths: &[Count],
+        displacements: &[Count],
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            blocklengths.len(),
+            displacements.len(),
+            "'blocklengths' and 'displacements' must be the same length"
+        );
+
+        let mut merged_blocklengths: Vec<Count> = Vec::with_capacity(blocklengths.len());
+        let mut merged_displacements: Vec<Count> = Vec::with_capacity(displacements.len());
+        for (&blocklength, &displacement) in blocklengths.iter().zip(displacements) {
+            if blocklength == 0 {
+                continue;
+            }
+            match (merged_blocklengths.last_mut(), merged_displacements.last()) {
+                (Some(last_blocklength), Some(&last_displacement))
+                    if last_displacement + *last_blocklength == displacement =>
+                {
+                    *last_blocklength += blocklength;
+                }
+                _ => {
+                    merged_blocklengths.push(blocklength);
+                    merged_displacements.push(displacement);
+                }
+            }
+        }
+
+        UncommittedUserDatatype::indexed(&merged_blocklengths, &merged_displacements, oldtype)
+    }
+
+    /// Constructs a new type out of multiple blocks of individual length and displacement.
+    /// Block `i` will be `blocklengths[i]` items of datytpe `oldtype` long and displaced by
+    /// `dispplacements[i]` bytes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn heterogeneous_indexed<D>(
+        blocklengths: &[Count],
+        displacements: &[Address],
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            blocklengths.len(),
+            displacements.len(),
+            "'blocklengths' and 'displacements' must be the same length"
+        );
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_hindexed(
+                        blocklengths.count(),
+                        blocklengths.as_ptr(),
+                        displacements.as_ptr() as *const MPI_Aint,
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Construct a new type out of blocks of the same length and individual displacements.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn indexed_block<D>(blocklength: Count, displacements: &[Count], oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_indexed_block(
+                        displacements.count(),
+                        blocklength,
+                        displacements.as_ptr(),
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Construct a new type out of blocks of the same length and individual displacements.
+    /// Displacements are in bytes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn heterogeneous_indexed_block<D>(
+        blocklength: Count,
+        displacements: &[Address],
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_hindexed_block(
+                        displacements.count(),
+                        blocklength,
+                        displacements.as_ptr() as *const MPI_Aint,
+                        oldtype.as_raw(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Constructs a new datatype out of blocks of different length, displacement and datatypes
+    ///
+    /// The elements of `types` do not need to share a concrete type. Trait objects such as
+    /// `Box<dyn Datatype<DuplicatedDatatype = UserDatatype>>` can be used to mix `SystemDatatype`s
+    /// and `UserDatatype`s in a layout that is only known at runtime.
+    ///
+    /// # Examples
+    /// See `examples/structured.rs` and `examples/structured_dyn.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn structured<D>(blocklengths: &[Count], displacements: &[Address], types: &[D]) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            blocklengths.len(),
+            displacements.len(),
+            "'displacements', 'blocklengths', and 'types' must be the same length"
+        );
+        assert_eq!(
+            blocklengths.len(),
+            types.len(),
             "'displacements', 'blocklengths', and 'types' must be the same length"
         );
 
-        unsafe {
-            UncommittedUserDatatype(
-                with_uninitialized(|newtype| {
-                    ffi::MPI_Type_create_struct(
-                        blocklengths.count(),
-                        blocklengths.as_ptr(),
-                        displacements.as_ptr(),
-                        types.as_ptr() as *const _,
-                        newtype,
-                    )
-                })
-                .1,
-            )
+        let types: SmallVec<[MPI_Datatype; 8]> = types.iter().map(|t| t.as_raw()).collect();
+
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_struct(
+                        blocklengths.count(),
+                        blocklengths.as_ptr(),
+                        displacements.as_ptr() as *const MPI_Aint,
+                        types.as_ptr(),
+                        newtype,
+                    )
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Constructs a new datatype out of runs of `count` consecutive elements of `datatype`, for
+    /// each pair `(datatype, count)` in `runs`, with each run directly following the previous one.
+    ///
+    /// # Examples
+    /// See `examples/runs.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn runs<D>(runs: &[(D, Count)]) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        let blocklengths: Vec<Count> = runs.iter().map(|&(_, count)| count).collect();
+        let displacements: Vec<Address> = runs
+            .iter()
+            .scan(Address(0), |displacement, (datatype, count)| {
+                let run_displacement = *displacement;
+                *displacement += *count * datatype.extent();
+                Some(run_displacement)
+            })
+            .collect();
+        let types: Vec<&D> = runs.iter().map(|(datatype, _)| datatype).collect();
+
+        UncommittedUserDatatype::structured(&blocklengths, &displacements, &types)
+    }
+
+    /// Constructs a new datatype describing row `row` of a sparse matrix in compressed sparse row
+    /// (CSR) format, i.e. both its column `indices` and its `values`.
+    ///
+    /// # Examples
+    /// See `examples/csr_row.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.5
+    pub fn csr_row(row_offsets: &[Count], indices: &[i32], values: &[f64], row: usize) -> Self {
+        let offset = |i: usize| -> usize {
+            row_offsets[i]
+                .value_as()
+                .expect("CSR row offset cannot be expressed as a usize.")
+        };
+        let (start, end) = (offset(row), offset(row + 1));
+        let row_indices = &indices[start..end];
+        let row_values = &values[start..end];
+
+        let len = row_indices.count();
+        UncommittedUserDatatype::structured(
+            &[len, len],
+            &[address_of(row_indices), address_of(row_values)],
+            &[i32::equivalent_datatype(), f64::equivalent_datatype()],
+        )
+    }
+
+    /// Constructs a new datatype describing a complex number stored as its real part immediately
+    /// followed by its imaginary part, both of type `T`.
+    ///
+    /// # Examples
+    /// See `examples/complex_layouts.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn interleaved_complex<T>() -> Self
+    where
+        T: Equivalence,
+    {
+        UncommittedUserDatatype::contiguous(2, &T::equivalent_datatype())
+    }
+
+    /// Constructs a new datatype describing `n` complex numbers stored with all `n` real parts
+    /// followed by all `n` imaginary parts, all of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is negative or `2 * n` exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/complex_layouts.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn split_complex<T>(n: Count) -> Self
+    where
+        T: Equivalence,
+    {
+        assert!(n >= 0, "'n' must not be negative");
+        // The imaginary parts start at `n`, so `n + i` for `i < n` is bounded by `2 * n`.
+        n.checked_mul(2)
+            .expect("'2 * n' exceeds the range of Count");
+
+        let displacements: Vec<Count> = (0..n)
+            .flat_map(|i| iter::once(i).chain(iter::once(n + i)))
+            .collect();
+        UncommittedUserDatatype::indexed_block(1, &displacements, &T::equivalent_datatype())
+    }
+
+    /// Constructs a new datatype describing the elements of a row-major `rows` x `cols` matrix of
+    /// `oldtype` in column-major order.
+    ///
+    /// # Examples
+    /// See `examples/transpose.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn transpose_2d<D>(rows: Count, cols: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        let column = UncommittedUserDatatype::vector(rows, 1, cols, oldtype);
+        // Consecutive columns start one element of `oldtype` apart.
+        let column = UncommittedUserDatatype::resized(&column, Address(0), oldtype.extent());
+        UncommittedUserDatatype::contiguous(cols, &column)
+    }
+
+    /// Constructs a new datatype describing field `field` of `count` records of `num_fields`
+    /// elements of `oldtype` stored as a struct of arrays, with the arrays of consecutive fields
+    /// placed `field_stride` elements apart.
+    ///
+    /// # Panics
+    ///
+    /// If `field` does not lie within `0..num_fields` or the offset of its array,
+    /// `field * field_stride`, exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/deinterleave.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn deinterleave<D>(
+        num_fields: Count,
+        field: Count,
+        count: Count,
+        field_stride: Count,
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            0 <= field && field < num_fields,
+            "'field' must lie within '0..num_fields'"
+        );
+        let start = field
+            .checked_mul(field_stride)
+            .expect("The offset of the field exceeds the range of Count");
+
+        // The values of the field are stored contiguously in its array.
+        UncommittedUserDatatype::indexed_block(count, &[start], oldtype)
+    }
+
+    /// Constructs a new datatype describing the diagonal of a row-major `n` x `n` matrix of
+    /// `oldtype`.
+    ///
+    /// # Examples
+    /// See `examples/diagonal.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn diagonal<D>(n: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        let displacements: Vec<Count> = (0..n).map(|i| i * (n + 1)).collect();
+        UncommittedUserDatatype::indexed_block(1, &displacements, oldtype)
+    }
+
+    /// Constructs a new datatype describing the upper or lower triangle, including the diagonal,
+    /// of a row-major `n` x `n` matrix of `oldtype`.
+    ///
+    /// # Examples
+    /// See `examples/triangular.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn triangular<D>(n: Count, kind: Triangle, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        // Row `i` contributes a single block, starting at or ending with its diagonal element.
+        let (blocklengths, displacements): (Vec<Count>, Vec<Count>) = (0..n)
+            .map(|i| match kind {
+                Triangle::Upper => (n - i, i * n + i),
+                Triangle::Lower => (i + 1, i * n),
+            })
+            .unzip();
+        UncommittedUserDatatype::indexed(&blocklengths, &displacements, oldtype)
+    }
+
+    /// Constructs a new datatype describing the upper or lower triangle, including the diagonal,
+    /// of an `n` x `n` matrix of `oldtype` stored in `order`, visiting its elements in the order
+    /// of packed storage.
+    ///
+    /// # Examples
+    /// See `examples/packed_triangular.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn packed_triangular<D>(n: Count, kind: Triangle, order: Order, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        // A column of a matrix stored in column-major order is laid out like a row of its
+        // transpose stored in row-major order, and transposing swaps the triangles.
+        let kind = match (order, kind) {
+            (Order::C, kind) => kind,
+            (Order::Fortran, Triangle::Upper) => Triangle::Lower,
+            (Order::Fortran, Triangle::Lower) => Triangle::Upper,
+        };
+        UncommittedUserDatatype::triangular(n, kind, oldtype)
+    }
+
+    /// Constructs a new datatype describing the cells of one `color` of a checkerboard pattern on
+    /// a row-major grid of `ny` rows of `nx` elements of `oldtype`.
+    ///
+    /// Cell `(x, y)`, i.e. element `y * nx + x`, is red if `x + y` is even and black otherwise, so
+    /// the first cell of the grid is red. This is an indexed datatype of single elements.
+    ///
+    /// # Panics
+    ///
+    /// If `nx` or `ny` is negative.
+    ///
+    /// # Examples
+    /// See `examples/checkerboard.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn checkerboard<D>(nx: Count, ny: Count, color: CellColor, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(nx >= 0 && ny >= 0, "'nx' and 'ny' must not be negative");
+        let parity = match color {
+            CellColor::Red => 0,
+            CellColor::Black => 1,
+        };
+        let displacements: Vec<Count> = (0..ny)
+            .flat_map(|y| ((y + parity) % 2..nx).step_by(2).map(move |x| y * nx + x))
+            .collect();
+        UncommittedUserDatatype::indexed_block(1, &displacements, oldtype)
+    }
+
+    /// Constructs a new datatype describing the `len` elements of `oldtype` starting at element
+    /// `offset` of a global sequence of `global_len` elements.
+    ///
+    /// # Examples
+    /// See `examples/file_block.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn file_block<D>(global_len: Count, offset: Count, len: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            offset >= 0 && len >= 0 && offset + len <= global_len,
+            "the block must lie within the global sequence"
+        );
+
+        let block = UncommittedUserDatatype::indexed_block(len, &[offset], oldtype);
+        let extent = oldtype.extent();
+        UncommittedUserDatatype::resized(&block, Address(0), global_len * extent)
+    }
+
+    /// Constructs a new datatype describing `interior` consecutive elements of `oldtype` out of
+    /// every `total`.
+    ///
+    /// # Examples
+    /// See `examples/padded.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn padded<D>(interior: Count, total: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            0 <= interior && interior <= total,
+            "'interior' must not exceed 'total'"
+        );
+
+        let block = UncommittedUserDatatype::contiguous(interior, oldtype);
+        let extent = oldtype.extent();
+        UncommittedUserDatatype::resized(&block, Address(0), total * extent)
+    }
+
+    /// Constructs a new datatype describing the elements of a global sequence of `global`
+    /// elements of `oldtype` that belong to process `rank` out of `nprocs` in a block-cyclic
+    /// distribution with blocks of `block` elements.
+    ///
+    /// # Examples
+    /// See `examples/block_cyclic.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn block_cyclic<D>(
+        global: Count,
+        block: Count,
+        nprocs: Count,
+        rank: Count,
+        oldtype: &D,
+    ) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(global >= 0, "'global' must not be negative");
+        assert!(block > 0, "'block' must be positive");
+        assert!(
+            0 <= rank && rank < nprocs,
+            "'rank' must be in the range 0..'nprocs'"
+        );
+
+        let (blocklengths, displacements): (Vec<Count>, Vec<Count>) = (rank * block..global)
+            .step_by(
+                (nprocs * block)
+                    .value_as()
+                    .expect("Block-cyclic stride cannot be expressed as a usize."),
+            )
+            .map(|start| (block.min(global - start), start))
+            .unzip();
+
+        let blocks = UncommittedUserDatatype::indexed(&blocklengths, &displacements, oldtype);
+        let extent = oldtype.extent();
+        UncommittedUserDatatype::resized(&blocks, Address(0), global * extent)
+    }
+
+    /// Constructs a new datatype describing an n-dimensional array of `oldtype` with the extents
+    /// `shape` and the byte strides `strides`.
+    ///
+    /// # Examples
+    /// See `examples/strided.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn strided<D>(shape: &[Count], strides: &[Address], oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert_eq!(
+            shape.len(),
+            strides.len(),
+            "'shape' and 'strides' must be the same length"
+        );
+
+        // Every axis is a vector of single blocks of the axes it contains, starting with the last.
+        shape.iter().zip(strides).rev().fold(
+            UncommittedUserDatatype::contiguous(1, oldtype),
+            |newtype, (&count, &stride)| {
+                UncommittedUserDatatype::heterogeneous_vector(count, 1, stride, &newtype)
+            },
+        )
+    }
+
+    /// Like `strided()` but `strides` are given in elements of `oldtype` rather than bytes.
+    ///
+    /// # Examples
+    /// See `examples/strided.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn from_strides<D>(shape: &[Count], strides: &[Count], oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        let extent = oldtype.extent();
+        let strides: Vec<Address> = strides.iter().map(|&stride| stride * extent).collect();
+        UncommittedUserDatatype::strided(shape, &strides, oldtype)
+    }
+
+    /// Constructs a new datatype describing the elements of `oldtype` in each of the `ranges`.
+    ///
+    /// # Panics
+    ///
+    /// If any range ends before it starts.
+    ///
+    /// # Examples
+    /// See `examples/from_ranges.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn from_ranges<D>(ranges: &[Range<Count>], oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            ranges.iter().all(|range| range.start <= range.end),
+            "'ranges' must not end before they start"
+        );
+
+        let (blocklengths, displacements): (Vec<Count>, Vec<Count>) = ranges
+            .iter()
+            .map(|range| (range.end - range.start, range.start))
+            .unzip();
+        UncommittedUserDatatype::indexed(&blocklengths, &displacements, oldtype)
+    }
+
+    /// Constructs a new datatype describing `len` consecutive elements of `oldtype` in a ring
+    /// buffer of `total` elements, starting at index `start` and wrapping around to the start of
+    /// the buffer if necessary.
+    ///
+    /// This is an indexed datatype of the block `start..total` followed by the block of the
+    /// remaining elements from index `0`, if any.
+    ///
+    /// # Panics
+    ///
+    /// If `start` does not lie within the buffer or `len` exceeds `total`.
+    ///
+    /// # Examples
+    /// See `examples/wraparound.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn wraparound<D>(total: Count, start: Count, len: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(
+            0 <= start && start < total,
+            "'start' must lie within the buffer"
+        );
+        assert!(
+            0 <= len && len <= total,
+            "'len' must not exceed the size of the buffer"
+        );
+
+        let first = len.min(total - start);
+        let remaining = len - first;
+        if remaining == 0 {
+            UncommittedUserDatatype::indexed(&[first], &[start], oldtype)
+        } else {
+            UncommittedUserDatatype::indexed(&[first, remaining], &[start, 0], oldtype)
+        }
+    }
+
+    /// Constructs a new datatype describing tiles of `tile` consecutive elements of `oldtype`
+    /// that cover a sequence of `total` elements, with consecutive tiles sharing `overlap`
+    /// elements.
+    ///
+    /// This is an indexed datatype with one block per tile. The tiles start every `tile -
+    /// overlap` elements, beginning at index `0`, until a tile reaches the end of the sequence.
+    /// That last tile is shortened to end with the sequence if necessary. Overlapping blocks are
+    /// only valid for sending, see `UserDatatype::overlapping_tiles()`.
+    ///
+    /// # Panics
+    ///
+    /// If `total` is negative, `tile` is not positive or `overlap` does not lie within `0..tile`.
+    ///
+    /// # Examples
+    /// See `examples/overlapping_tiles.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn overlapping_tiles<D>(total: Count, tile: Count, overlap: Count, oldtype: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        assert!(total >= 0, "'total' must not be negative");
+        assert!(tile > 0, "'tile' must be positive");
+        assert!(
+            0 <= overlap && overlap < tile,
+            "'overlap' must lie within '0..tile'"
+        );
+
+        let mut blocklengths = Vec::new();
+        let mut displacements = Vec::new();
+        let mut start = 0;
+        while start < total {
+            blocklengths.push(tile.min(total - start));
+            displacements.push(start);
+            if start + tile >= total {
+                break;
+            }
+            start += tile - overlap;
+        }
+        UncommittedUserDatatype::indexed(&blocklengths, &displacements, oldtype)
+    }
+
+    /// Constructs a new datatype describing the field of type `F` at byte `offset` within a
+    /// value of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// If the field does not lie within `T`.
+    ///
+    /// # Examples
+    /// See `examples/field_of.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2, 4.1.7
+    pub fn field_of<T, F>(offset: Address) -> Self
+    where
+        F: Equivalence,
+    {
+        let size = Address::try_from(size_of::<T>())
+            .expect("Struct size cannot be expressed as an MPI Address.");
+        let field_size = Address::try_from(size_of::<F>())
+            .expect("Field size cannot be expressed as an MPI Address.");
+        assert!(
+            Address(0) <= offset && offset + field_size <= size,
+            "'offset' must place the field within the struct"
+        );
+
+        let field =
+            UncommittedUserDatatype::structured(&[1], &[offset], &[F::equivalent_datatype()]);
+        UncommittedUserDatatype::resized(&field, Address(0), size)
+    }
+
+    /// Constructs a new datatype describing this datatype directly followed by `next`.
+    ///
+    /// # Examples
+    /// See `examples/then.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.2
+    pub fn then<D>(&self, next: &D) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        UncommittedUserDatatype::sequence(self, next)
+    }
+
+    fn sequence<D, E>(first: &D, next: &E) -> Self
+    where
+        D: UncommittedDatatype,
+        E: UncommittedDatatype,
+    {
+        let types = unsafe {
+            [
+                UncommittedDatatypeRef::from_raw(first.as_raw()),
+                UncommittedDatatypeRef::from_raw(next.as_raw()),
+            ]
+        };
+        // The lower bound of `next` is placed at the upper bound of `first`.
+        let displacement = first.lower_bound() + first.extent() - next.lower_bound();
+        UncommittedUserDatatype::structured(&[1, 1], &[Address(0), displacement], &types)
+    }
+
+    /// Rebuilds a datatype from a description of how it was constructed.
+    ///
+    /// A descriptor of a predefined datatype is rebuilt as a duplicate of that datatype.
+    ///
+    /// # Errors
+    ///
+    /// If the descriptor names an unknown predefined datatype, uses a constructor that cannot be
+    /// rebuilt, or does not hold the arguments its constructor expects.
+    ///
+    /// # Examples
+    /// See `examples/layout_descriptor.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.13
+    pub fn from_descriptor(descriptor: &LayoutDescriptor) -> Result<Self, DescriptorError> {
+        match descriptor.build()? {
+            DecodedDatatype::Predefined(datatype) => {
+                Ok(UncommittedDatatypeRef::from(datatype).dup())
+            }
+            DecodedDatatype::Derived(datatype) => Ok(datatype),
+        }
+    }
+
+    /// Constructs a new datatype identical to `oldtype` but with its lower bound set to
+    /// `lower_bound` and its extent set to `extent`.
+    ///
+    /// See `UserDatatype::resized()` for datatypes with an extent of zero.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.7
+    pub fn resized<D>(oldtype: &D, lower_bound: Address, extent: Address) -> Self
+    where
+        D: UncommittedDatatype,
+    {
+        unsafe {
+            UncommittedUserDatatype::from_raw(
+                with_uninitialized(|newtype| {
+                    ffi::MPI_Type_create_resized(oldtype.as_raw(), lower_bound.0, extent.0, newtype)
+                })
+                .1,
+            )
+        }
+    }
+
+    /// Commits a datatype to a specific representation so that it can be used in MPI calls.
+    ///
+    /// # Panics
+    ///
+    /// If MPI reports that committing failed, see `try_commit()`.
+    ///
+    /// # Standard section(s)
+    /// 4.1.9
+    pub fn commit(self) -> UserDatatype {
+        self.try_commit()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Commits a datatype to a specific representation so that it can be used in MPI calls, or
+    /// returns the error MPI reported.
+    ///
+    /// A `UserDatatype` is only created once the commit succeeded. Otherwise the uncommitted
+    /// datatype is freed right away. MPI only reports errors instead of aborting if the error
+    /// handler `MPI_ERRORS_RETURN` has been set.
+    ///
+    /// # Errors
+    ///
+    /// If `MPI_Type_commit()` returns an error code.
+    ///
+    /// # Examples
+    /// See `examples/try_commit.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.9, 8.3
+    pub fn try_commit(mut self) -> Result<UserDatatype, CommitError> {
+        let code = unsafe { ffi::MPI_Type_commit(&mut self.0) };
+        let success: c_int = ffi::MPI_SUCCESS
+            .value_as()
+            .expect("MPI_SUCCESS does not fit into c_int");
+        if code != success {
+            return Err(CommitError { code });
+        }
+
+        let handle = self.0;
+        unmark_uncommitted(handle);
+        mem::forget(self);
+        let datatype = UserDatatype(handle);
+        trace_datatype!("committed", datatype);
+        Ok(datatype)
+    }
+
+    /// A datatype whose commit always fails, to test how code that commits datatypes handles
+    /// errors.
+    ///
+    /// It wraps `MPI_DATATYPE_NULL`, so it must not be used for anything but `try_commit()`,
+    /// which only returns an error instead of aborting if the error handler `MPI_ERRORS_RETURN`
+    /// has been set. This is intended to be used in the test suites of downstream crates and is
+    /// only available with the `testing` feature.
+    ///
+    /// # Examples
+    /// See `examples/commit_all_error.rs`
+    #[cfg(feature = "testing")]
+    pub fn uncommittable() -> Self {
+        UncommittedUserDatatype(unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL))
+    }
+
+    /// Creates an UncommittedDatatypeRef from this datatype object.
+    pub fn as_ref(&self) -> UncommittedDatatypeRef<'_> {
+        unsafe { UncommittedDatatypeRef::from_raw(self.as_raw()) }
+    }
+}
+
+impl Clone for UncommittedUserDatatype {
+    fn clone(&self) -> Self {
+        self.dup()
+    }
+}
+
+impl Drop for UncommittedUserDatatype {
+    fn drop(&mut self) {
+        let null = unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL);
+        // Only the datatype returned by `uncommittable()` is null and there is nothing to free.
+        if self.0 == null {
+            return;
+        }
+        trace_datatype!("freeing", self);
+        unsafe {
+            ffi::MPI_Type_free(&mut self.0);
+        }
+        assert_eq!(self.0, null);
+    }
+}
+
+unsafe impl AsRaw for UncommittedUserDatatype {
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl FromRaw for UncommittedUserDatatype {
+    unsafe fn from_raw(handle: MPI_Datatype) -> Self {
+        assert_ne!(handle, ffi::RSMPI_DATATYPE_NULL);
+        mark_uncommitted(handle);
+        let datatype = UncommittedUserDatatype(handle);
+        trace_datatype!("created", datatype);
+        datatype
+    }
+}
+
+unsafe impl MatchesRaw for UncommittedUserDatatype {}
+
+/// Formats the constructor of the datatype, or with `{:#?}` the whole tree of constructors as
+/// described by `fmt_tree()`.
+impl fmt::Debug for UncommittedUserDatatype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            self.fmt_tree(f, 0)
+        } else {
+            f.debug_tuple("UncommittedUserDatatype")
+                .field(&self.envelope().combiner)
+                .finish()
+        }
+    }
+}
+
+impl UncommittedDatatype for UncommittedUserDatatype {
+    type DuplicatedDatatype = UncommittedUserDatatype;
+}
+
+impl<'a> From<&'a UncommittedUserDatatype> for UncommittedDatatypeRef<'a> {
+    fn from(datatype: &'a UncommittedUserDatatype) -> Self {
+        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
+    }
+}
+
+/// Selects a triangle of a square matrix, see `UserDatatype::triangular()`
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Triangle {
+    /// The diagonal and all elements above it
+    Upper,
+    /// The diagonal and all elements below it
+    Lower,
+}
+
+/// The color of a cell of a checkerboard pattern, see `UserDatatype::checkerboard()`
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CellColor {
+    /// The cells `(x, y)` with even `x + y`, including the first cell of the grid
+    Red,
+    /// The cells `(x, y)` with odd `x + y`
+    Black,
+}
+
+/// The order in which the elements of a multi-dimensional array are stored, see
+/// `UserDatatype::subarray()`
+///
+/// # Standard section(s)
+///
+/// 4.1.3
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Order {
+    /// Row-major order as used by C and Rust, the last dimension varies fastest
+    C,
+    /// Column-major order as used by Fortran, the first dimension varies fastest
+    Fortran,
+}
+
+impl Order {
+    fn as_raw(self) -> c_int {
+        match self {
+            Order::C => unsafe_extern_static!(ffi::RSMPI_ORDER_C),
+            Order::Fortran => unsafe_extern_static!(ffi::RSMPI_ORDER_FORTRAN),
+        }
+    }
+
+    fn from_raw(order: c_int) -> Option<Self> {
+        [Order::C, Order::Fortran]
+            .iter()
+            .copied()
+            .find(|o| o.as_raw() == order)
+    }
+}
+
+/// A side of a two-dimensional grid, see `Halo2D`
+///
+/// North is the side of the first row, west the side of the first column.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Side {
+    /// The side of the first row
+    North,
+    /// The side of the last row
+    South,
+    /// The side of the last column
+    East,
+    /// The side of the first column
+    West,
+}
+
+impl Side {
+    /// All sides in the order used by `Halo2D`
+    pub const ALL: [Side; 4] = [Side::North, Side::South, Side::East, Side::West];
+
+    /// The side opposite to this one
+    pub fn opposite(self) -> Side {
+        match self {
+            Side::North => Side::South,
+            Side::South => Side::North,
+            Side::East => Side::West,
+            Side::West => Side::East,
+        }
+    }
+}
+
+/// The boundary regions of a row-major two-dimensional grid of `T` surrounded by a halo of
+/// ghost cells.
+///
+/// The grid has `rows` x `cols` elements including a halo that is `width` elements wide on every
+/// side. For every side, the grid contains two regions of the same shape: the boundary of the
+/// interior next to that side, which is sent to the neighbour on that side, and the halo on that
+/// side, which receives the boundary of that neighbour. Both are described by `datatype(side)`
+/// starting at `boundary_offset(side)` and `halo_offset(side)` respectively. The corners of the
+/// halo are not part of any region.
+///
+/// See `point_to_point::exchange_halo()` for exchanging all four sides in one call.
+///
+/// # Examples
+/// See `examples/halo_2d.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2
+pub struct Halo2D<T> {
+    rows: Count,
+    cols: Count,
+    width: Count,
+    north_south: UserDatatype,
+    east_west: UserDatatype,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Halo2D<T>
+where
+    T: Equivalence,
+{
+    /// Describes the boundary regions of a `rows` x `cols` grid, including a halo of `width`
+    /// elements on every side.
+    ///
+    /// # Panics
+    ///
+    /// If the grid has no interior, i.e. `rows` or `cols` is not larger than `2 * width`, if
+    /// `width` is not positive or if the number of elements of the grid, `rows * cols`, exceeds
+    /// the range of `Count` or `usize`.
+    pub fn new(rows: Count, cols: Count, width: Count) -> Self {
+        assert!(width > 0, "'width' must be positive");
+        let both_halos = width
+            .checked_mul(2)
+            .expect("Twice the 'width' exceeds the range of Count");
+        assert!(
+            rows > both_halos && cols > both_halos,
+            "'rows' and 'cols' must be larger than twice the 'width'"
+        );
+        // Every index into the grid is below its number of elements, so checking that once here
+        // rules out overflows when computing offsets later on.
+        rows.checked_mul(cols)
+            .and_then(|len| len.value_as::<usize>().ok())
+            .expect("The number of elements of the grid exceeds the range of Count or usize");
+
+        let element = T::equivalent_datatype();
+
+        Halo2D {
+            rows,
+            cols,
+            width,
+            north_south: UserDatatype::vector(width, cols - both_halos, cols, &element),
+            east_west: UserDatatype::vector(rows - both_halos, width, cols, &element),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Halo2D<T> {
+    /// The number of rows of the grid, including the halo
+    pub fn rows(&self) -> Count {
+        self.rows
+    }
+
+    /// The number of columns of the grid, including the halo
+    pub fn cols(&self) -> Count {
+        self.cols
+    }
+
+    /// The width of the halo
+    pub fn width(&self) -> Count {
+        self.width
+    }
+
+    /// The number of elements of the grid, including the halo, i.e. `rows() * cols()`
+    pub fn grid_len(&self) -> usize {
+        self.rows
+            .checked_mul(self.cols)
+            .and_then(|len| len.value_as().ok())
+            .expect("The number of elements of the grid cannot be expressed as a usize.")
+    }
+
+    /// The datatype describing the boundary and halo regions on `side`
+    pub fn datatype(&self, side: Side) -> &UserDatatype {
+        match side {
+            Side::North | Side::South => &self.north_south,
+            Side::East | Side::West => &self.east_west,
+        }
+    }
+
+    /// The index of the first element of the boundary of the interior next to `side`
+    pub fn boundary_offset(&self, side: Side) -> usize {
+        let (rows, cols, width) = (self.rows, self.cols, self.width);
+        let (row, col) = match side {
+            Side::North => (width, width),
+            Side::South => (rows - 2 * width, width),
+            Side::East => (width, cols - 2 * width),
+            Side::West => (width, width),
+        };
+        self.index(row, col)
+    }
+
+    /// The index of the first element of the halo on `side`
+    pub fn halo_offset(&self, side: Side) -> usize {
+        let (rows, cols, width) = (self.rows, self.cols, self.width);
+        let (row, col) = match side {
+            Side::North => (0, width),
+            Side::South => (rows - width, width),
+            Side::East => (width, cols - width),
+            Side::West => (width, 0),
+        };
+        self.index(row, col)
+    }
+
+    fn index(&self, row: Count, col: Count) -> usize {
+        row.checked_mul(self.cols)
+            .and_then(|start| start.checked_add(col))
+            .and_then(|index| index.value_as().ok())
+            .expect("Grid index cannot be expressed as a usize.")
+    }
+}
+
+/// A field of a record as described to `UserDatatype::from_layout()`
+#[derive(Copy, Clone, Debug)]
+pub struct Field {
+    /// The datatype of the elements of the field
+    pub base: SystemDatatype,
+    /// The offset of the field in bytes from the start of the record
+    pub offset: Address,
+    /// The number of consecutive elements in the field
+    pub count: Count,
+}
+
+/// Describes the layout of a struct one field at a time.
+///
+/// This is a fluent alternative to `UserDatatype::structured()`. Each field is described by the
+/// `Equivalence` of its type and its displacement from the start of the struct, e.g. as computed
+/// by `memoffset::offset_of!`.
+///
+/// # Examples
+/// See `examples/struct_builder.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2
+pub struct StructTypeBuilder {
+    blocklengths: Vec<Count>,
+    displacements: Vec<Address>,
+    types: Vec<Box<dyn AsRaw<Raw = MPI_Datatype>>>,
+}
+
+impl StructTypeBuilder {
+    /// Starts describing a struct without any fields.
+    pub fn new() -> Self {
+        StructTypeBuilder {
+            blocklengths: Vec::new(),
+            displacements: Vec::new(),
+            types: Vec::new(),
+        }
+    }
+
+    /// Adds a field of type `T` located `offset` bytes from the start of the struct.
+    pub fn field<T>(self, offset: Address) -> Self
+    where
+        T: Equivalence,
+        T::Out: 'static,
+    {
+        self.array_field::<T>(offset, 1)
+    }
+
+    /// Adds a field holding `len` consecutive values of type `T` (e.g. `[T; len]`) located
+    /// `offset` bytes from the start of the struct.
+    pub fn array_field<T>(mut self, offset: Address, len: Count) -> Self
+    where
+        T: Equivalence,
+        T::Out: 'static,
+    {
+        self.blocklengths.push(len);
+        self.displacements.push(offset);
+        self.types.push(Box::new(T::equivalent_datatype()));
+        self
+    }
+
+    /// Commits the described layout to a new datatype.
+    pub fn build(&self) -> UserDatatype {
+        let types: SmallVec<[UncommittedDatatypeRef<'_>; 8]> = self
+            .types
+            .iter()
+            .map(|t| unsafe { UncommittedDatatypeRef::from_raw(t.as_raw()) })
+            .collect();
+        UserDatatype::structured(&self.blocklengths, &self.displacements, &types)
+    }
+}
+
+impl Default for StructTypeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Commits a batch of datatypes, e.g. all derived datatypes an application registers up front.
+///
+/// The committed datatypes are returned in the same order. Each datatype is committed via
+/// `UncommittedUserDatatype::try_commit()`, so MPI only reports errors instead of aborting if the
+/// error handler `MPI_ERRORS_RETURN` has been set.
+///
+/// # Errors
+///
+/// Stops at the first datatype that cannot be committed and returns its index in the batch
+/// together with the error. The datatypes committed before it and the ones after it are freed.
+///
+/// # Examples
+/// See `examples/commit_all.rs`
+///
+/// # Standard section(s)
+/// 4.1.9, 8.3
+pub fn commit_all<I>(types: I) -> Result<Vec<UserDatatype>, (usize, CommitError)>
+where
+    I: IntoIterator<Item = UncommittedUserDatatype>,
+{
+    types
+        .into_iter()
+        .enumerate()
+        .map(|(i, datatype)| datatype.try_commit().map_err(|error| (i, error)))
+        .collect()
+}
+
+static DATATYPE_CACHE: Lazy<Mutex<HashMap<TypeId, UserDatatype>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Returns the datatype cached for the type `T`, constructing it via `build` on first use.
+///
+/// This is meant for implementations of `Equivalence` whose datatype has to be constructed, so
+/// that it is constructed only once and shared between all threads. `#[derive(Equivalence)]` uses
+/// it as well. If two threads race to construct the datatype, the one cached first is returned to
+/// both and the other one is freed.
+///
+/// Below `Threading::Multiple`, constructing the datatype is subject to the same restrictions as
+/// any other MPI call, see the module documentation.
+///
+/// All cached datatypes are freed when the `Universe` is dropped, right before `MPI_Finalize()`
+/// is called. The returned reference must not be used after that.
+///
+/// # Examples
+/// See `examples/cached_datatype.rs`
+pub fn cached_datatype<T, F>(build: F) -> DatatypeRef<'static>
+where
+    T: 'static + ?Sized,
+    F: FnOnce() -> UserDatatype,
+{
+    let key = TypeId::of::<T>();
+
+    if let Some(datatype) = DATATYPE_CACHE
+        .lock()
+        .expect("Datatype cache is poisoned.")
+        .get(&key)
+    {
+        return unsafe { DatatypeRef::from_raw_unchecked(datatype.as_raw()) };
+    }
+
+    // The lock is not held while constructing the datatype, as `build` may itself look up the
+    // cached datatypes of the fields of `T`.
+    let datatype = build();
+
+    let mut cache = DATATYPE_CACHE.lock().expect("Datatype cache is poisoned.");
+    let datatype = cache.entry(key).or_insert(datatype);
+    unsafe { DatatypeRef::from_raw_unchecked(datatype.as_raw()) }
+}
+
+/// Frees all datatypes cached by `cached_datatype()`
+pub(crate) fn free_cached_datatypes() {
+    DATATYPE_CACHE
+        .lock()
+        .expect("Datatype cache is poisoned.")
+        .clear();
+}
+
+/// A Datatype describes the layout of messages in memory.
+///
+/// `Datatype` always represents a committed datatype that can be immediately used for sending and
+/// receiving messages. `UncommittedDatatype` is used for datatypes that are possibly uncommitted.
+///
+/// Both `Datatype` and `UncommittedDatatype` are object safe. A collection of datatypes that do
+/// not share a concrete type can be stored as e.g. `Box<dyn Datatype<DuplicatedDatatype =
+/// UserDatatype>>`.
+pub trait Datatype: UncommittedDatatype {}
+impl<'a, D> Datatype for &'a D where D: 'a + Datatype + ?Sized {}
+impl<D> Datatype for Box<D> where D: Datatype + ?Sized {}
+
+/// An UncommittedDatatype is a partial description of the layout of messages in memory which may
+/// not yet have been committed to an implementation-defined message format.
+///
+/// Committed datatypes can be treated as-if they are uncommitted.
+pub trait UncommittedDatatype: AsRaw<Raw = MPI_Datatype> {
+    /// The type returned when the datatype is duplicated.
+    type DuplicatedDatatype: FromRaw<Raw = MPI_Datatype>;
+
+    /// Creates a new datatype with the same key-values as this datatype.
+    ///
+    /// # Standard section(s)
+    /// 4.1.10
+    fn dup(&self) -> Self::DuplicatedDatatype {
+        unsafe {
+            Self::DuplicatedDatatype::from_raw(
+                with_uninitialized(|newtype| ffi::MPI_Type_dup(self.as_raw(), newtype)).1,
+            )
+        }
+    }
+
+    /// The lower bound of the datatype, i.e. the displacement of its first byte relative to the
+    /// start of a buffer.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.7
+    fn lower_bound(&self) -> Address {
+        Address(unsafe {
+            with_uninitialized2(|lower_bound, extent| {
+                ffi::MPI_Type_get_extent(self.as_raw(), lower_bound, extent)
+            })
+            .1
+        })
+    }
+
+    /// The extent of the datatype, i.e. the distance in bytes between consecutive elements of
+    /// this datatype in a buffer.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.7
+    fn extent(&self) -> Address {
+        Address(unsafe {
+            with_uninitialized2(|lower_bound, extent| {
+                ffi::MPI_Type_get_extent(self.as_raw(), lower_bound, extent)
+            })
+            .2
+        })
+    }
+
+    /// The number of bytes spanned by `count` consecutive elements of this datatype in a buffer,
+    /// i.e. `count` times the extent.
+    ///
+    /// This is what has to be allocated for `count` elements. Unlike `size()` it includes any gaps
+    /// within and between the elements.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.7
+    fn bytes_for(&self, count: Count) -> Address {
+        count * self.extent()
+    }
+
+    /// The number of complete consecutive elements of this datatype that fit into `bytes` bytes,
+    /// i.e. `bytes` divided by the extent and rounded down.
+    ///
+    /// # Panics
+    ///
+    /// If the extent of the datatype is zero or the number of elements exceeds the range of
+    /// `Count`.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.7
+    fn elements_in(&self, bytes: Address) -> Count {
+        let extent = self.extent();
+        assert_ne!(
+            extent,
+            Address(0),
+            "The datatype must not have an extent of zero"
+        );
+        (bytes.0 / extent.0)
+            .value_as()
+            .expect("The number of elements exceeds the range of Count")
+    }
+
+    /// The true lower bound of the datatype, i.e. the displacement of the first byte actually
+    /// occupied by data, ignoring any artificial bounds set e.g. by `resized()`.
+    ///
+    /// # Standard section(s)
+    /// 4.1.8
+    fn true_lower_bound(&self) -> Address {
+        Address(unsafe {
+            with_uninitialized2(|lower_bound, extent| {
+                ffi::MPI_Type_get_true_extent(self.as_raw(), lower_bound, extent)
+            })
+            .1
+        })
+    }
+
+    /// The true extent of the datatype, i.e. the number of bytes spanned by the data it
+    /// describes, ignoring any artificial bounds set e.g. by `resized()`.
+    ///
+    /// # Standard section(s)
+    /// 4.1.8
+    fn true_extent(&self) -> Address {
+        Address(unsafe {
+            with_uninitialized2(|lower_bound, extent| {
+                ffi::MPI_Type_get_true_extent(self.as_raw(), lower_bound, extent)
+            })
+            .2
+        })
+    }
+
+    /// Whether this is the null datatype, `MPI_DATATYPE_NULL`.
+    ///
+    /// None of the other queries may be used on the null datatype.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    fn is_null(&self) -> bool {
+        self.as_raw() == unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL)
+    }
+
+    /// The number of bytes of data described by the datatype, i.e. excluding any gaps.
+    ///
+    /// # Examples
+    /// See `examples/f90_datatypes.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.5
+    fn size(&self) -> Count {
+        unsafe { with_uninitialized(|size| ffi::MPI_Type_size(self.as_raw(), size)).1 }
+    }
+
+    /// Like `size()` but the number of bytes may exceed the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/contiguous_large.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.5
+    fn size_x(&self) -> LargeCount {
+        unsafe { with_uninitialized(|size| ffi::MPI_Type_size_x(self.as_raw(), size)).1 }
+    }
+
+    /// Describes how the datatype was constructed and how many arguments were involved.
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn envelope(&self) -> Envelope {
+        let mut num_integers = 0;
+        let mut num_addresses = 0;
+        let mut num_datatypes = 0;
+        let mut combiner = 0;
+        unsafe {
+            ffi::MPI_Type_get_envelope(
+                self.as_raw(),
+                &mut num_integers,
+                &mut num_addresses,
+                &mut num_datatypes,
+                &mut combiner,
+            );
+        }
+        Envelope {
+            num_integers,
+            num_addresses,
+            num_datatypes,
+            combiner: Combiner::from(combiner),
+        }
+    }
+
+    /// Whether data described by this datatype can be converted between processes with different
+    /// data representations, e.g. on clusters mixing byte orders or word sizes.
+    ///
+    /// This is the case if all the predefined datatypes the datatype is built from are typed,
+    /// e.g. `MPI_DOUBLE`. MPI transfers `MPI_BYTE` and `MPI_PACKED` unchanged, so datatypes built
+    /// from them, like those of `ByteBuffer` or of the `half` floating point types, are not
+    /// portable. Datatypes that cannot be decoded, e.g. those built by `MPI_Type_create_darray()`,
+    /// are conservatively reported as not portable.
+    ///
+    /// # Examples
+    /// See `examples/portable.rs`
+    ///
+    /// # Standard section(s)
+    /// 3.3.1, 4.1.13
+    fn is_portable(&self) -> bool {
+        let byte = unsafe_extern_static!(ffi::RSMPI_BYTE);
+        let packed = unsafe_extern_static!(ffi::RSMPI_PACKED);
+        match type_signature(self) {
+            Some(signature) => signature
+                .iter()
+                .all(|&(datatype, _)| datatype != byte && datatype != packed),
+            None => false,
+        }
+    }
+
+    /// The predefined datatypes this datatype is built from, in the order in which they occur in
+    /// its type signature, or `None` if it was constructed in a way that cannot be decoded, e.g. by
+    /// `MPI_Type_create_darray()`.
+    ///
+    /// Each entry is a run of consecutive elements of the same predefined datatype together with
+    /// its length, so that large counts do not have to be spelled out element by element, e.g.
+    /// both `contiguous(4, &f64::equivalent_datatype())` and a struct of two fields of two `f64`s
+    /// each yield `[(f64, 4)]`, while a struct of an `i32`, an `f64` and another `i32` yields
+    /// `[(i32, 1), (f64, 1), (i32, 1)]`. A predefined datatype yields itself once.
+    ///
+    /// # Examples
+    /// See `examples/leaf_types.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn leaf_types(&self) -> Option<Vec<(SystemDatatype, usize)>> {
+        let signature = type_signature(self)?;
+        Some(
+            signature
+                .into_iter()
+                .map(|(datatype, len)| (unsafe { DatatypeRef::from_raw_unchecked(datatype) }, len))
+                .collect(),
+        )
+    }
+
+    /// Whether this is a predefined datatype, e.g. `MPI_DOUBLE`, rather than a derived one.
+    ///
+    /// Predefined datatypes are owned by the MPI library and must never be freed. This includes
+    /// the datatypes returned by e.g. `SystemDatatype::f90_real()`.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn is_predefined(&self) -> bool {
+        matches!(
+            self.envelope().combiner,
+            Combiner::Named | Combiner::F90Real | Combiner::F90Complex | Combiner::F90Integer
+        )
+    }
+
+    /// The arguments that were used to construct the datatype.
+    ///
+    /// Returns `None` for predefined datatypes, which were not constructed from other datatypes.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn contents(&self) -> Option<Contents> {
+        let envelope = self.envelope();
+        if envelope.combiner == Combiner::Named {
+            return None;
+        }
+
+        let len = |count: Count| -> usize {
+            count
+                .value_as()
+                .expect("Datatype argument count cannot be expressed as a usize.")
+        };
+        let mut integers = vec![0; len(envelope.num_integers)];
+        let mut addresses = vec![Address(0); len(envelope.num_addresses)];
+        let mut datatypes =
+            vec![unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL); len(envelope.num_datatypes)];
+        unsafe {
+            ffi::MPI_Type_get_contents(
+                self.as_raw(),
+                envelope.num_integers,
+                envelope.num_addresses,
+                envelope.num_datatypes,
+                integers.as_mut_ptr(),
+                addresses.as_mut_ptr() as *mut MPI_Aint,
+                datatypes.as_mut_ptr(),
+            );
+        }
+
+        Some(Contents {
+            combiner: envelope.combiner,
+            integers,
+            addresses,
+            datatypes: datatypes
+                .into_iter()
+                .map(|datatype| unsafe { DecodedDatatype::from_raw(datatype) })
+                .collect(),
+        })
+    }
+
+    /// A human readable name of the datatype.
+    ///
+    /// This is the name MPI reports for the datatype, e.g. `MPI_DOUBLE` for `f64`, or that was
+    /// given to it. Derived datatypes without a name are named after their constructor, e.g.
+    /// `Vector`.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    /// 6.8
+    fn type_name(&self) -> String {
+        let name = datatype_name(self.as_raw());
+        if name.is_empty() {
+            format!("{:?}", self.envelope().combiner)
+        } else {
+            name
+        }
+    }
+
+    /// The predefined datatype all elements of this datatype consist of.
+    ///
+    /// Predefined datatypes are their own base type. For derived datatypes, the datatypes they
+    /// were constructed from are decoded down to the predefined datatypes. Returns `None` if
+    /// these differ, e.g. for a struct with fields of different types.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn base_type(&self) -> Option<SystemDatatype> {
+        if self.is_predefined() {
+            return Some(unsafe { DatatypeRef::from_raw_unchecked(self.as_raw()) });
+        }
+
+        let contents = self.contents()?;
+        let mut base_types = contents
+            .datatypes
+            .iter()
+            .map(|datatype| datatype.base_type());
+        let first = base_types.next()??;
+        if base_types.all(|base_type| base_type.map(|b| b.as_raw()) == Some(first.as_raw())) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    /// Writes a description of how the datatype was constructed to `f` as an indented tree.
+    ///
+    /// Each line describes one datatype: predefined datatypes by their name, derived datatypes by
+    /// the constructor and its integer and address arguments, followed by the datatypes they were
+    /// constructed from, indented by two more spaces. The first line is indented by `indent`
+    /// spaces and no trailing newline is written.
+    ///
+    /// This is what the alternate form of `Debug`, i.e. `{:#?}`, uses for user datatypes.
+    ///
+    /// # Examples
+    /// See `examples/datatype_contents.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn fmt_tree(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        write!(f, "{:indent$}", "", indent = indent)?;
+
+        if self.is_null() {
+            return write!(f, "MPI_DATATYPE_NULL");
+        }
+
+        match self.contents() {
+            None => write!(f, "{}", self.type_name()),
+            Some(contents) => {
+                write!(
+                    f,
+                    "{:?} {{ integers: {:?}, addresses: {:?} }}",
+                    contents.combiner, contents.integers, contents.addresses
+                )?;
+                for datatype in &contents.datatypes {
+                    writeln!(f)?;
+                    datatype.fmt_tree(f, indent + 2)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// A description of how the datatype was constructed that does not refer to any MPI handles.
+    ///
+    /// Predefined datatypes are described by their name, derived datatypes by their constructor,
+    /// its arguments and the descriptions of the datatypes they were constructed from. The
+    /// datatype can be rebuilt from this by `UserDatatype::from_descriptor()`, possibly on another
+    /// process.
+    ///
+    /// # Examples
+    /// See `examples/layout_descriptor.rs`
+    ///
+    /// # Standard section(s)
+    /// 4.1.13
+    fn to_descriptor(&self) -> LayoutDescriptor {
+        match self.contents() {
+            None => LayoutDescriptor::Predefined(datatype_name(self.as_raw())),
+            Some(contents) => LayoutDescriptor::Derived {
+                combiner: contents.combiner,
+                integers: contents.integers,
+                addresses: contents.addresses,
+                datatypes: contents
+                    .datatypes
+                    .iter()
+                    .map(|datatype| datatype.to_descriptor())
+                    .collect(),
+            },
+        }
+    }
+}
+impl<'a, D> UncommittedDatatype for &'a D
+where
+    D: 'a + UncommittedDatatype + ?Sized,
+{
+    type DuplicatedDatatype = <D as UncommittedDatatype>::DuplicatedDatatype;
+}
+impl<D> UncommittedDatatype for Box<D>
+where
+    D: UncommittedDatatype + ?Sized,
+{
+    type DuplicatedDatatype = <D as UncommittedDatatype>::DuplicatedDatatype;
+}
+
+/// `MPI_BYTE`, which is transferred without any conversion
+fn byte_datatype() -> SystemDatatype {
+    let byte = unsafe_extern_static!(ffi::RSMPI_BYTE);
+    unsafe { DatatypeRef::from_raw_unchecked(byte) }
+}
+
+/// The name MPI reports for a datatype, e.g. `MPI_DOUBLE` for predefined datatypes.
+fn datatype_name(datatype: MPI_Datatype) -> String {
+    type BufType = [c_char; ffi::MPI_MAX_OBJECT_NAME as usize];
+
+    unsafe {
+        let mut buf = MaybeUninit::<BufType>::uninit();
+
+        let (_, _resultlen) = with_uninitialized(|resultlen| {
+            ffi::MPI_Type_get_name(datatype, &mut (*buf.as_mut_ptr())[0], resultlen)
+        });
+
+        let buf_cstr = CStr::from_ptr(buf.assume_init().as_ptr());
+        buf_cstr.to_string_lossy().into_owned()
+    }
+}
+
+/// The constructor that was used to create a datatype
+///
+/// # Standard section(s)
+/// 4.1.13
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Combiner {
+    /// A predefined datatype
+    Named,
+    /// `UncommittedDatatype::dup()`
+    Dup,
+    /// `UserDatatype::contiguous()`
+    Contiguous,
+    /// `UserDatatype::vector()`
+    Vector,
+    /// `UserDatatype::heterogeneous_vector()`
+    HeterogeneousVector,
+    /// `UserDatatype::indexed()`
+    Indexed,
+    /// `UserDatatype::heterogeneous_indexed()`
+    HeterogeneousIndexed,
+    /// `UserDatatype::indexed_block()`
+    IndexedBlock,
+    /// `UserDatatype::heterogeneous_indexed_block()`
+    HeterogeneousIndexedBlock,
+    /// `UserDatatype::structured()`
+    Structured,
+    /// `UserDatatype::subarray()`
+    Subarray,
+    /// `MPI_Type_create_darray()`
+    Darray,
+    /// `MPI_Type_create_f90_real()`
+    F90Real,
+    /// `MPI_Type_create_f90_complex()`
+    F90Complex,
+    /// `MPI_Type_create_f90_integer()`
+    F90Integer,
+    /// `UserDatatype::resized()`
+    Resized,
+    /// Any other constructor by its raw value, e.g. the deprecated `MPI_COMBINER_HVECTOR_INTEGER`
+    /// or one added by a later version of MPI
+    Unknown(c_int),
+}
+
+impl From<c_int> for Combiner {
+    fn from(i: c_int) -> Combiner {
+        let combiners = unsafe_extern_static!([
+            (ffi::RSMPI_COMBINER_NAMED, Combiner::Named),
+            (ffi::RSMPI_COMBINER_DUP, Combiner::Dup),
+            (ffi::RSMPI_COMBINER_CONTIGUOUS, Combiner::Contiguous),
+            (ffi::RSMPI_COMBINER_VECTOR, Combiner::Vector),
+            (ffi::RSMPI_COMBINER_HVECTOR, Combiner::HeterogeneousVector),
+            (ffi::RSMPI_COMBINER_INDEXED, Combiner::Indexed),
+            (ffi::RSMPI_COMBINER_HINDEXED, Combiner::HeterogeneousIndexed),
+            (ffi::RSMPI_COMBINER_INDEXED_BLOCK, Combiner::IndexedBlock),
+            (
+                ffi::RSMPI_COMBINER_HINDEXED_BLOCK,
+                Combiner::HeterogeneousIndexedBlock
+            ),
+            (ffi::RSMPI_COMBINER_STRUCT, Combiner::Structured),
+            (ffi::RSMPI_COMBINER_SUBARRAY, Combiner::Subarray),
+            (ffi::RSMPI_COMBINER_DARRAY, Combiner::Darray),
+            (ffi::RSMPI_COMBINER_F90_REAL, Combiner::F90Real),
+            (ffi::RSMPI_COMBINER_F90_COMPLEX, Combiner::F90Complex),
+            (ffi::RSMPI_COMBINER_F90_INTEGER, Combiner::F90Integer),
+            (ffi::RSMPI_COMBINER_RESIZED, Combiner::Resized),
+        ]);
+        combiners
+            .iter()
+            .find(|&&(raw, _)| raw == i)
+            .map(|&(_, combiner)| combiner)
+            .unwrap_or(Combiner::Unknown(i))
+    }
+}
+
+/// The number of arguments of each kind that were used to construct a datatype
+///
+/// # Standard section(s)
+/// 4.1.13
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Envelope {
+    /// The number of integer arguments
+    pub num_integers: Count,
+    /// The number of address arguments
+    pub num_addresses: Count,
+    /// The number of datatype arguments
+    pub num_datatypes: Count,
+    /// The constructor that was used
+    pub combiner: Combiner,
+}
+
+/// The arguments that were used to construct a datatype
+///
+/// The arguments are laid out as described in section 4.1.13 of the MPI standard, e.g. for
+/// `Combiner::Structured`, `integers` holds the number of blocks followed by the block lengths,
+/// `addresses` holds the displacements and `datatypes` holds the type of each block.
+///
+/// # Standard section(s)
+/// 4.1.13
+pub struct Contents {
+    /// The constructor that was used
+    pub combiner: Combiner,
+    /// The integer arguments
+    pub integers: Vec<c_int>,
+    /// The address arguments
+    pub addresses: Vec<Address>,
+    /// The datatype arguments
+    pub datatypes: Vec<DecodedDatatype>,
+}
+
+/// A datatype that was used to construct another datatype as returned by
+/// `UncommittedDatatype::contents()`
+///
+/// MPI hands out new datatype objects for derived datatypes which are freed when this is dropped.
+/// Whether these are committed is unspecified, so they are treated as uncommitted.
+pub enum DecodedDatatype {
+    /// A predefined datatype
+    Predefined(SystemDatatype),
+    /// A derived datatype
+    Derived(UncommittedUserDatatype),
+}
+
+impl DecodedDatatype {
+    unsafe fn from_raw(handle: MPI_Datatype) -> Self {
+        if UncommittedDatatypeRef::from_raw(handle).is_predefined() {
+            DecodedDatatype::Predefined(DatatypeRef::from_raw_unchecked(handle))
+        } else {
+            // MPI may return the handle of a datatype that is still in use, committed or not, so
+            // it is neither marked as uncommitted nor is an existing mark removed.
+            let datatype = UncommittedUserDatatype(handle);
+            trace_datatype!("created", datatype);
+            DecodedDatatype::Derived(datatype)
+        }
+    }
+}
+
+unsafe impl AsRaw for DecodedDatatype {
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        match *self {
+            DecodedDatatype::Predefined(ref datatype) => datatype.as_raw(),
+            DecodedDatatype::Derived(ref datatype) => datatype.as_raw(),
+        }
+    }
+}
+
+impl UncommittedDatatype for DecodedDatatype {
+    type DuplicatedDatatype = UncommittedUserDatatype;
+}
+
+impl Contents {
+    /// The arguments split up by their meaning, or `None` if they cannot be decoded
+    fn arguments(&self) -> Option<Arguments<'_>> {
+        Arguments::parse(
+            self.combiner,
+            &self.integers,
+            &self.addresses,
+            self.datatypes.len(),
+        )
+    }
+}
+
+/// The arguments of a datatype constructor as laid out in `Contents` or `LayoutDescriptor`, split
+/// up by their meaning as described in section 4.1.13 of the MPI standard
+enum Arguments<'a> {
+    Dup,
+    Contiguous {
+        count: c_int,
+    },
+    Vector {
+        count: c_int,
+        blocklength: c_int,
+        stride: c_int,
+    },
+    HeterogeneousVector {
+        count: c_int,
+        blocklength: c_int,
+        stride: Address,
+    },
+    Indexed {
+        blocklengths: &'a [c_int],
+        displacements: &'a [c_int],
+    },
+    HeterogeneousIndexed {
+        blocklengths: &'a [c_int],
+        displacements: &'a [Address],
+    },
+    IndexedBlock {
+        blocklength: c_int,
+        displacements: &'a [c_int],
+    },
+    HeterogeneousIndexedBlock {
+        blocklength: c_int,
+        displacements: &'a [Address],
+    },
+    Structured {
+        blocklengths: &'a [c_int],
+        displacements: &'a [Address],
+    },
+    Subarray {
+        sizes: &'a [c_int],
+        subsizes: &'a [c_int],
+        starts: &'a [c_int],
+        order: Order,
+    },
+    Resized {
+        lower_bound: Address,
+        extent: Address,
+    },
+    F90Real {
+        precision: c_int,
+        range: c_int,
+    },
+    F90Complex {
+        precision: c_int,
+        range: c_int,
+    },
+    F90Integer {
+        range: c_int,
+    },
+}
+
+/// A block of `blocklength` consecutive elements of the datatype argument at index `datatype`,
+/// starting at byte `displacement`
+struct Block {
+    blocklength: c_int,
+    displacement: Address,
+    datatype: usize,
+}
+
+impl<'a> Arguments<'a> {
+    /// Splits up the arguments of `combiner`, or returns `None` if it cannot be decoded or if the
+    /// number of arguments does not match what it expects.
+    fn parse(
+        combiner: Combiner,
+        integers: &'a [c_int],
+        addresses: &'a [Address],
+        num_datatypes: usize,
+    ) -> Option<Self> {
+        // The number of blocks, which is the first integer argument of most constructors
+        let num_blocks = || -> Option<usize> { (*integers.first()?).value_as().ok() };
+        let expect = |num_integers: usize, num_addresses: usize, num_datatypes_expected: usize| {
+            if integers.len() == num_integers
+                && addresses.len() == num_addresses
+                && num_datatypes == num_datatypes_expected
+            {
+                Some(())
+            } else {
+                None
+            }
+        };
+
+        let arguments = match combiner {
+            Combiner::Dup => {
+                expect(0, 0, 1)?;
+                Arguments::Dup
+            }
+            Combiner::Contiguous => {
+                expect(1, 0, 1)?;
+                Arguments::Contiguous { count: integers[0] }
+            }
+            Combiner::Vector => {
+                expect(3, 0, 1)?;
+                Arguments::Vector {
+                    count: integers[0],
+                    blocklength: integers[1],
+                    stride: integers[2],
+                }
+            }
+            Combiner::HeterogeneousVector => {
+                expect(2, 1, 1)?;
+                Arguments::HeterogeneousVector {
+                    count: integers[0],
+                    blocklength: integers[1],
+                    stride: addresses[0],
+                }
+            }
+            Combiner::Indexed => {
+                let n = num_blocks()?;
+                expect(1 + 2 * n, 0, 1)?;
+                Arguments::Indexed {
+                    blocklengths: &integers[1..=n],
+                    displacements: &integers[n + 1..],
+                }
+            }
+            Combiner::HeterogeneousIndexed => {
+                let n = num_blocks()?;
+                expect(1 + n, n, 1)?;
+                Arguments::HeterogeneousIndexed {
+                    blocklengths: &integers[1..],
+                    displacements: addresses,
+                }
+            }
+            Combiner::IndexedBlock => {
+                let n = num_blocks()?;
+                expect(2 + n, 0, 1)?;
+                Arguments::IndexedBlock {
+                    blocklength: integers[1],
+                    displacements: &integers[2..],
+                }
+            }
+            Combiner::HeterogeneousIndexedBlock => {
+                let n = num_blocks()?;
+                expect(2, n, 1)?;
+                Arguments::HeterogeneousIndexedBlock {
+                    blocklength: integers[1],
+                    displacements: addresses,
+                }
+            }
+            Combiner::Structured => {
+                let n = num_blocks()?;
+                expect(1 + n, n, n)?;
+                Arguments::Structured {
+                    blocklengths: &integers[1..],
+                    displacements: addresses,
+                }
+            }
+            Combiner::Subarray => {
+                let n = num_blocks()?;
+                expect(2 + 3 * n, 0, 1)?;
+                Arguments::Subarray {
+                    sizes: &integers[1..=n],
+                    subsizes: &integers[n + 1..=2 * n],
+                    starts: &integers[2 * n + 1..=3 * n],
+                    order: Order::from_raw(integers[1 + 3 * n])?,
+                }
+            }
+            Combiner::Resized => {
+                expect(0, 2, 1)?;
+                Arguments::Resized {
+                    lower_bound: addresses[0],
+                    extent: addresses[1],
+                }
+            }
+            Combiner::F90Real => {
+                expect(2, 0, 0)?;
+                Arguments::F90Real {
+                    precision: integers[0],
+                    range: integers[1],
+                }
+            }
+            Combiner::F90Complex => {
+                expect(2, 0, 0)?;
+                Arguments::F90Complex {
+                    precision: integers[0],
+                    range: integers[1],
+                }
+            }
+            Combiner::F90Integer => {
+                expect(1, 0, 0)?;
+                Arguments::F90Integer { range: integers[0] }
+            }
+            Combiner::Named | Combiner::Darray | Combiner::Unknown(_) => return None,
+        };
+        Some(arguments)
+    }
+
+    /// The blocks the datatype consists of in the order of its type map, given its `datatypes`
+    /// arguments, or `None` if it is not built from other datatypes.
+    ///
+    /// A sub-array is described by one block per row of its fastest varying dimension.
+    fn blocks<D>(&self, datatypes: &[D]) -> Option<Vec<Block>>
+    where
+        D: UncommittedDatatype,
+    {
+        // Displacements in elements are multiples of the extent of the only datatype argument.
+        let extent = || datatypes[0].extent();
+        let block = |blocklength: c_int, displacement: Address| Block {
+            blocklength,
+            displacement,
+            datatype: 0,
+        };
+
+        let blocks = match *self {
+            Arguments::Dup | Arguments::Resized { .. } => vec![block(1, Address(0))],
+            Arguments::Contiguous { count } => vec![block(count, Address(0))],
+            Arguments::Vector {
+                count,
+                blocklength,
+                stride,
+            } => (0..count)
+                .map(|i| block(blocklength, extent() * (i as MPI_Aint * stride as MPI_Aint)))
+                .collect(),
+            Arguments::HeterogeneousVector {
+                count,
+                blocklength,
+                stride,
+            } => (0..count)
+                .map(|i| block(blocklength, stride * i as MPI_Aint))
+                .collect(),
+            Arguments::Indexed {
+                blocklengths,
+                displacements,
+            } => blocklengths
+                .iter()
+                .zip(displacements)
+                .map(|(&blocklength, &displacement)| block(blocklength, displacement * extent()))
+                .collect(),
+            Arguments::HeterogeneousIndexed {
+                blocklengths,
+                displacements,
+            } => blocklengths
+                .iter()
+                .zip(displacements)
+                .map(|(&blocklength, &displacement)| block(blocklength, displacement))
+                .collect(),
+            Arguments::IndexedBlock {
+                blocklength,
+                displacements,
+            } => displacements
+                .iter()
+                .map(|&displacement| block(blocklength, displacement * extent()))
+                .collect(),
+            Arguments::HeterogeneousIndexedBlock {
+                blocklength,
+                displacements,
+            } => displacements
+                .iter()
+                .map(|&displacement| block(blocklength, displacement))
+                .collect(),
+            Arguments::Structured {
+                blocklengths,
+                displacements,
+            } => blocklengths
+                .iter()
+                .zip(displacements)
+                .enumerate()
+                .map(|(i, (&blocklength, &displacement))| Block {
+                    blocklength,
+                    displacement,
+                    datatype: i,
+                })
+                .collect(),
+            Arguments::Subarray {
+                sizes,
+                subsizes,
+                starts,
+                order,
+            } => {
+                // Dimensions ordered from the slowest to the fastest varying one
+                let mut dims: Vec<usize> = (0..sizes.len()).collect();
+                if order == Order::Fortran {
+                    dims.reverse();
+                }
+                let (&fastest, slower) = dims.split_last()?;
+
+                let mut blocks = Vec::new();
+                let mut index: Vec<c_int> = vec![0; sizes.len()];
+                while subsizes.iter().all(|&subsize| subsize > 0) {
+                    let offset = dims.iter().fold(0, |offset: MPI_Aint, &d| {
+                        offset * sizes[d] as MPI_Aint + starts[d] as MPI_Aint + index[d] as MPI_Aint
+                    });
+                    blocks.push(block(subsizes[fastest], extent() * offset));
+
+                    // Advance to the next row of the sub-block, fastest dimension first
+                    match slower.iter().rev().find(|&&d| index[d] + 1 < subsizes[d]) {
+                        Some(&d) => {
+                            index[d] += 1;
+                            for &faster in slower.iter().skip_while(|&&e| e != d).skip(1) {
+                                index[faster] = 0;
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                blocks
+            }
+            Arguments::F90Real { .. }
+            | Arguments::F90Complex { .. }
+            | Arguments::F90Integer { .. } => return None,
+        };
+        Some(blocks)
+    }
+}
+
+/// Checks whether data sent with datatype `a` can be received with datatype `b`, i.e. whether
+/// their type signatures match.
+///
+/// The type signature of a datatype is the sequence of predefined datatypes it consists of,
+/// irrespective of where they are placed in memory. MPI requires the signatures of matching send
+/// and receive operations to agree, so e.g. a vector of four `i32` in two blocks matches a
+/// contiguous datatype of four `i32`. Sending fewer elements than the receive datatype describes
+/// is permitted by MPI but is not considered a match here.
+///
+/// Datatypes constructed by `MPI_Type_create_darray()` cannot be decoded and never match.
+///
+/// # Examples
+/// See `examples/signatures_match.rs`
+///
+/// # Standard section(s)
+///
+/// 3.3.1, 4.1.11
+pub fn signatures_match<A, B>(a: &A, b: &B) -> bool
+where
+    A: ?Sized + UncommittedDatatype,
+    B: ?Sized + UncommittedDatatype,
+{
+    match (type_signature(a), type_signature(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// The type signature of a datatype as runs of the same predefined datatype, or `None` if it
+/// cannot be decoded
+fn type_signature<D>(datatype: &D) -> Option<Vec<(MPI_Datatype, usize)>>
+where
+    D: ?Sized + UncommittedDatatype,
+{
+    fn push(signature: &mut Vec<(MPI_Datatype, usize)>, part: &[(MPI_Datatype, usize)], n: usize) {
+        if let [(datatype, len)] = *part {
+            return push_run(signature, datatype, len * n);
+        }
+        for _ in 0..n {
+            for &(datatype, len) in part {
+                push_run(signature, datatype, len);
+            }
+        }
+    }
+
+    fn push_run(signature: &mut Vec<(MPI_Datatype, usize)>, datatype: MPI_Datatype, len: usize) {
+        match signature.last_mut() {
+            _ if len == 0 => {}
+            Some(&mut (last, ref mut last_len)) if last == datatype => *last_len += len,
+            _ => signature.push((datatype, len)),
+        }
+    }
+
+    if datatype.is_predefined() {
+        return Some(vec![(datatype.as_raw(), 1)]);
+    }
+
+    let contents = datatype.contents()?;
+    let blocks = contents.arguments()?.blocks(&contents.datatypes)?;
+    let parts = contents
+        .datatypes
+        .iter()
+        .map(type_signature)
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut signature = Vec::new();
+    for block in blocks {
+        let len = block
+            .blocklength
+            .value_as()
+            .expect("Datatype block length cannot be expressed as a usize.");
+        push(&mut signature, &parts[block.datatype], len);
+    }
+    Some(signature)
+}
+
+/// The byte ranges accessed by one instance of `datatype` relative to its start, in the order of
+/// its type map and with adjacent ranges merged, or `None` if it was constructed in a way that
+/// cannot be decoded.
+fn type_map<D>(datatype: &D) -> Option<Vec<Range<Address>>>
+where
+    D: ?Sized + UncommittedDatatype,
+{
+    fn push(map: &mut Vec<Range<Address>>, part: &[Range<Address>], displacement: Address) {
+        for range in part {
+            let range = range.start + displacement..range.end + displacement;
+            match map.last_mut() {
+                _ if range.start == range.end => {}
+                Some(last) if last.end == range.start => last.end = range.end,
+                _ => map.push(range),
+            }
+        }
+    }
+
+    if datatype.is_predefined() {
+        let start = datatype.true_lower_bound();
+        return Some(iter::once(start..start + datatype.true_extent()).collect());
+    }
+
+    let contents = datatype.contents()?;
+    let blocks = contents.arguments()?.blocks(&contents.datatypes)?;
+    let parts = contents
+        .datatypes
+        .iter()
+        .map(type_map)
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut map = Vec::new();
+    for block in blocks {
+        let extent = contents.datatypes[block.datatype].extent();
+        for j in 0..block.blocklength {
+            push(
+                &mut map,
+                &parts[block.datatype],
+                block.displacement + j * extent,
+            );
+        }
+    }
+    Some(map)
+}
+
+/// Two blocks of a datatype that occupy overlapping regions of memory
+///
+/// `first` and `second` are the same block if consecutive elements within it overlap.
+///
+/// Returned by `UserDatatype::validate_non_overlapping()` and `UserDatatype::try_vector()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct OverlapError {
+    /// The index of the block that starts first
+    pub first: usize,
+    /// The index of the block that overlaps it
+    pub second: usize,
+}
+
+impl fmt::Display for OverlapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "datatype blocks {} and {} overlap",
+            self.first, self.second
+        )
+    }
+}
+
+impl Error for OverlapError {}
+
+/// A sub-block that does not lie within its array in dimension `dim`
+///
+/// Returned by `UserDatatype::try_subarray()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct SubarrayBoundsError {
+    /// The index of the offending dimension
+    pub dim: usize,
+    /// The number of elements of the array in that dimension
+    pub size: Count,
+    /// The number of elements of the sub-block in that dimension
+    pub subsize: Count,
+    /// The index at which the sub-block starts in that dimension
+    pub start: Count,
+}
+
+impl fmt::Display for SubarrayBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "sub-block of {} elements starting at {} does not fit into the {} elements of \
+             dimension {}",
+            self.subsize, self.start, self.size, self.dim
+        )
+    }
+}
+
+impl Error for SubarrayBoundsError {}
+
+/// A description of how a datatype was constructed that does not refer to any MPI handles
+///
+/// This mirrors `Contents` with the datatype arguments described recursively. With the `serde`
+/// feature enabled, it can be serialized, e.g. to persist datatype definitions or to send them to
+/// other processes.
+///
+/// Returned by `UncommittedDatatype::to_descriptor()`, rebuilt by
+/// `UserDatatype::from_descriptor()`.
+///
+/// # Standard section(s)
+/// 4.1.13
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LayoutDescriptor {
+    /// A predefined datatype given by the name MPI reports for it, e.g. `MPI_DOUBLE`
+    Predefined(String),
+    /// A derived datatype
+    Derived {
+        /// The constructor that was used
+        combiner: Combiner,
+        /// The integer arguments
+        integers: Vec<c_int>,
+        /// The address arguments
+        addresses: Vec<Address>,
+        /// The descriptions of the datatype arguments
+        datatypes: Vec<LayoutDescriptor>,
+    },
+}
+
+impl LayoutDescriptor {
+    fn build(&self) -> Result<DecodedDatatype, DescriptorError> {
+        let (combiner, integers, addresses, datatypes) = match *self {
+            LayoutDescriptor::Predefined(ref name) => {
+                return predefined_datatypes()
+                    .iter()
+                    .find(|datatype| datatype_name(datatype.as_raw()) == *name)
+                    .map(|&datatype| DecodedDatatype::Predefined(datatype))
+                    .ok_or_else(|| DescriptorError::UnknownPredefined(name.clone()));
+            }
+            LayoutDescriptor::Derived {
+                combiner,
+                ref integers,
+                ref addresses,
+                ref datatypes,
+            } => (combiner, &integers[..], &addresses[..], datatypes),
+        };
+
+        if let Combiner::Named | Combiner::Darray | Combiner::Unknown(_) = combiner {
+            return Err(DescriptorError::Unsupported(combiner));
+        }
+        let arguments = Arguments::parse(combiner, integers, addresses, datatypes.len())
+            .ok_or(DescriptorError::Malformed(combiner))?;
+        let undefined = unsafe_extern_static!(ffi::RSMPI_UNDEFINED);
+        let optional = |i: c_int| if i == undefined { None } else { Some(i) };
+
+        let datatypes = datatypes
+            .iter()
+            .map(LayoutDescriptor::build)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let datatype = match arguments {
+            Arguments::Dup => datatypes[0].dup(),
+            Arguments::Contiguous { count } => {
+                UncommittedUserDatatype::contiguous(count, &datatypes[0])
+            }
+            Arguments::Vector {
+                count,
+                blocklength,
+                stride,
+            } => UncommittedUserDatatype::vector(count, blocklength, stride, &datatypes[0]),
+            Arguments::HeterogeneousVector {
+                count,
+                blocklength,
+                stride,
+            } => UncommittedUserDatatype::heterogeneous_vector(
+                count,
+                blocklength,
+                stride,
+                &datatypes[0],
+            ),
+            Arguments::Indexed {
+                blocklengths,
+                displacements,
+            } => UncommittedUserDatatype::indexed(blocklengths, displacements, &datatypes[0]),
+            Arguments::HeterogeneousIndexed {
+                blocklengths,
+                displacements,
+            } => UncommittedUserDatatype::heterogeneous_indexed(
+                blocklengths,
+                displacements,
+                &datatypes[0],
+            ),
+            Arguments::IndexedBlock {
+                blocklength,
+                displacements,
+            } => UncommittedUserDatatype::indexed_block(blocklength, displacements, &datatypes[0]),
+            Arguments::HeterogeneousIndexedBlock {
+                blocklength,
+                displacements,
+            } => UncommittedUserDatatype::heterogeneous_indexed_block(
+                blocklength,
+                displacements,
+                &datatypes[0],
+            ),
+            Arguments::Structured {
+                blocklengths,
+                displacements,
+            } => UncommittedUserDatatype::structured(blocklengths, displacements, &datatypes),
+            Arguments::Resized {
+                lower_bound,
+                extent,
+            } => UncommittedUserDatatype::resized(&datatypes[0], lower_bound, extent),
+            Arguments::Subarray {
+                sizes,
+                subsizes,
+                starts,
+                order,
+            } => UncommittedUserDatatype::subarray(sizes, subsizes, starts, order, &datatypes[0]),
+            Arguments::F90Real { precision, range }
+            | Arguments::F90Complex { precision, range } => {
+                let (precision, range) = (optional(precision), optional(range));
+                if precision.is_none() && range.is_none() {
+                    return Err(DescriptorError::Malformed(combiner));
+                }
+                return Ok(DecodedDatatype::Predefined(
+                    if combiner == Combiner::F90Real {
+                        SystemDatatype::f90_real(precision, range)
+                    } else {
+                        SystemDatatype::f90_complex(precision, range)
+                    },
+                ));
+            }
+            Arguments::F90Integer { range } => {
+                return Ok(DecodedDatatype::Predefined(SystemDatatype::f90_integer(
+                    range,
+                )));
+            }
+        };
+        Ok(DecodedDatatype::Derived(datatype))
+    }
+}
+
+/// The predefined datatypes a `LayoutDescriptor` can refer to
+fn predefined_datatypes() -> Vec<SystemDatatype> {
+    let mut datatypes: Vec<_> = supported_system_datatypes()
+        .into_iter()
+        .map(|(_, datatype)| datatype)
+        .collect();
+    datatypes.push(byte_datatype());
+    datatypes
+}
+
+/// A `LayoutDescriptor` that a datatype cannot be rebuilt from
+///
+/// Returned by `UserDatatype::from_descriptor()`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DescriptorError {
+    /// The name of a predefined datatype that is not known
+    UnknownPredefined(String),
+    /// A constructor that datatypes cannot be rebuilt with
+    Unsupported(Combiner),
+    /// A constructor whose arguments do not match what it expects
+    Malformed(Combiner),
+}
+
+impl fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            DescriptorError::UnknownPredefined(ref name) => {
+                write!(f, "unknown predefined datatype {}", name)
+            }
+            DescriptorError::Unsupported(combiner) => {
+                write!(f, "datatypes cannot be rebuilt with {:?}", combiner)
+            }
+            DescriptorError::Malformed(combiner) => {
+                write!(f, "malformed arguments for {:?}", combiner)
+            }
+        }
+    }
+}
+
+impl Error for DescriptorError {}
+
+/// An error code returned by `MPI_Type_commit()`
+///
+/// Returned by `UncommittedUserDatatype::try_commit()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct CommitError {
+    /// The MPI error code
+    pub code: c_int,
+}
+
+impl fmt::Display for CommitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "committing the datatype failed with error code {}",
+            self.code
+        )
+    }
+}
+
+impl Error for CommitError {}
+
+/// `count` instances of a datatype that do not fit into a buffer of `size` bytes
+///
+/// Returned by `View::try_with_count_and_datatype()` and `SafeView::new()`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct OutOfBoundsError {
+    /// The number of instances of the datatype
+    pub count: Count,
+    /// The size of the buffer in bytes
+    pub size: usize,
+}
+
+impl fmt::Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{} instances of the datatype do not fit into a buffer of {} bytes",
+            self.count, self.size
+        )
+    }
+}
+
+impl Error for OutOfBoundsError {}
+
+/// Checks that `count` instances of `datatype` only touch the first `size` bytes of a buffer.
+fn check_bounds<D>(datatype: &D, count: Count, size: usize) -> Result<(), OutOfBoundsError>
+where
+    D: UncommittedDatatype,
+{
+    let error = OutOfBoundsError { count, size };
+    if count < 0 {
+        return Err(error);
+    }
+    if count == 0 {
+        return Ok(());
+    }
+
+    // The instances are laid out `extent` bytes apart, each touching the bytes from its true
+    // lower bound up to its true extent.
+    let extent = datatype.extent();
+    let true_lower_bound = datatype.true_lower_bound();
+    let true_extent = datatype.true_extent();
+    let span = extent
+        .checked_mul((count - 1).value_as().map_err(|_| error)?)
+        .ok_or(error)?;
+    let lowest = true_lower_bound
+        .checked_add(span.min(Address(0)))
+        .ok_or(error)?;
+    let highest = true_lower_bound
+        .checked_add(true_extent)
+        .and_then(|end| end.checked_add(span.max(Address(0))))
+        .ok_or(error)?;
+    let size_bytes = Address::try_from(size).map_err(|_| error)?;
+
+    if lowest >= Address(0) && highest <= size_bytes {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
+
+/// Something that has an associated datatype
+pub unsafe trait AsDatatype {
+    /// The type of the associated MPI datatype (e.g. `SystemDatatype` or `UserDatatype`)
+    type Out: Datatype;
+    /// The associated MPI datatype
+    fn as_datatype(&self) -> Self::Out;
+}
+
+unsafe impl<T> AsDatatype for T
+where
+    T: Equivalence,
+{
+    type Out = <T as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <T as Equivalence>::equivalent_datatype()
+    }
+}
+
+unsafe impl<T> AsDatatype for [T]
+where
+    T: Equivalence,
+{
+    type Out = <T as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <T as Equivalence>::equivalent_datatype()
+    }
+}
+
+#[doc(hidden)]
+pub mod internal {
+    use super::Equivalence;
+
+    /// Returns the datatype equivalent to the field projected by `field`, used by
+    /// `struct_datatype!`
+    pub fn field_datatype<S, F>(_field: fn(&S) -> &F) -> <F as Equivalence>::Out
+    where
+        F: Equivalence,
+    {
+        F::equivalent_datatype()
+    }
+
+    #[cfg(feature = "derive")]
+    pub fn check_derive_equivalence_universe_state(type_name: &str) {
+        use crate::environment::UNIVERSE_STATE;
+
+        // Take lock on UNIVERSE_STATE to prevent racing with mpi::init and mpi::finalize.
+        let universe_state = UNIVERSE_STATE.read().unwrap();
+
+        if !crate::environment::is_initialized() {
+            panic!(
+                "\n\
+                 RSMPI PANIC: Pre-MPI_Init datatype initialization\n\
+                 \n\
+                 Application attempted to initialize datatype of #[derive(Equivalence)] for \
+                 `{}` before initializing rsmpi. You must first initialize rsmpi before attempting \
+                 to use a custom type in an MPI call.\n",
+                type_name
+            );
+        }
+
+        let universe_state = universe_state.as_ref().unwrap();
+
+        if crate::environment::is_finalized() {
+            panic!(
+                "\n\
+                 RSMPI PANIC: Post-MPI_Finalize datatype initialization.\n\
+                 \n\
+                 Application attempted to initialize datatype of #[derive(Equivalence)] for \
+                 `{0}` after finalizing rsmpi. You must not attempt to use `{0}` in an MPI context \
+                 after finalizing rsmpi.\n",
+                type_name
+            );
+        }
+
+        if crate::environment::threading_support() != crate::Threading::Multiple {
+            if universe_state.main_thread != std::thread::current().id() {
+                panic!(
+                    "\n\
+                     RSMPI PANIC: Invalid threaded datatype initialization\n\
+                     \n\
+                     Application attempted to initialize the datatype of #[derive(Equivalence)]
+                     for `{0}` from a different thread than that which initialized `rsmpi`. This \
+                     is only supported when rsmpi is initialized with \
+                     `mpi::Threading::Multiple`. Please explicitly call \
+                     `{0}::equivalent_datatype()` at least once from the same thread as you call \
+                     `rsmpi::initialize*`, or initialize MPI using \
+                     `mpi::initialize_with_threading(mpi::Threading::Multiple)`.\n",
+                    type_name
+                )
+            }
+        }
+    }
+}
+
+/// A countable collection of things.
+pub unsafe trait Collection {
+    /// How many things are in this collection.
+    fn count(&self) -> Count;
+
+    /// The number of bytes spanned by the buffer, i.e. `count()` times the extent of its
+    /// datatype.
+    ///
+    /// This can be used to check that a buffer is large enough before handing it to MPI.
+    ///
+    /// # Examples
+    /// See `examples/resized.rs`
+    fn byte_extent(&self) -> Address
+    where
+        Self: AsDatatype,
+    {
+        self.count() * self.as_datatype().extent()
+    }
+}
+
+unsafe impl<T> Collection for T
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        1
+    }
+}
+
+unsafe impl<T> Collection for [T]
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
+/// Provides a pointer to the starting address in memory.
+pub unsafe trait Pointer {
+    /// A pointer to the starting address in memory
+    unsafe fn pointer(&self) -> *const c_void;
+}
+
+unsafe impl<T> Pointer for T
+where
+    T: Equivalence,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        let p: *const T = self;
+        p as *const c_void
+    }
+}
+
+unsafe impl<T> Pointer for [T]
+where
+    T: Equivalence,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        self.as_ptr() as _
+    }
+}
+
+/// Provides a mutable pointer to the starting address in memory.
+pub unsafe trait PointerMut {
+    /// A mutable pointer to the starting address in memory
+    unsafe fn pointer_mut(&mut self) -> *mut c_void;
+}
+
+unsafe impl<T> PointerMut for T
+where
+    T: Equivalence,
+{
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        let p: *mut T = self;
+        p as *mut c_void
+    }
+}
+
+unsafe impl<T> PointerMut for [T]
+where
+    T: Equivalence,
+{
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.as_mut_ptr() as _
+    }
+}
+
+/// A buffer is a region in memory that starts at `pointer()` and contains `count()` copies of
+/// `as_datatype()`.
+pub unsafe trait Buffer: Pointer + Collection + AsDatatype {
+    /// An upper bound on the number of bytes needed to pack this buffer in `comm`, e.g. to size
+    /// the output of `Communicator::pack_into()`.
+    ///
+    /// This is `comm.pack_size(self.count(), &self.as_datatype())`.
+    ///
+    /// # Examples
+    /// See `examples/pack.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2, see MPI_Pack_size
+    fn packed_size<C>(&self, comm: &C) -> Count
+    where
+        C: ?Sized + Communicator,
+    {
+        comm.pack_size(self.count(), &self.as_datatype())
+    }
+
+    /// A buffer with the same address and datatype that only contains the first `n` copies of
+    /// `as_datatype()`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is negative or larger than `count()`.
+    ///
+    /// # Examples
+    /// See `examples/truncated.rs`
+    fn truncated(&self, n: Count) -> Truncated<'_, Self> {
+        Truncated::new(self, n)
+    }
+}
+unsafe impl<T> Buffer for T where T: Equivalence {}
+unsafe impl<T> Buffer for [T] where T: Equivalence {}
+
+/// A mutable buffer is a region in memory that starts at `pointer_mut()` and contains `count()`
+/// copies of `as_datatype()`.
+pub unsafe trait BufferMut: PointerMut + Collection + AsDatatype {
+    /// A mutable buffer with the same address and datatype that only contains the first `n`
+    /// copies of `as_datatype()`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is negative or larger than `count()`.
+    ///
+    /// # Examples
+    /// See `examples/truncated.rs`
+    fn truncated_mut(&mut self, n: Count) -> TruncatedMut<'_, Self> {
+        TruncatedMut::new(self, n)
+    }
+}
+unsafe impl<T> BufferMut for T where T: Equivalence {}
+unsafe impl<T> BufferMut for [T] where T: Equivalence {}
+
+unsafe impl<T> AsDatatype for [MaybeUninit<T>]
+where
+    T: Equivalence,
+{
+    type Out = <T as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <T as Equivalence>::equivalent_datatype()
+    }
+}
+
+unsafe impl<T> Collection for [MaybeUninit<T>]
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
+unsafe impl<T> PointerMut for [MaybeUninit<T>]
+where
+    T: Equivalence,
+{
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.as_mut_ptr() as _
+    }
+}
+
+/// Receiving into uninitialized memory avoids having to initialize a buffer that will be
+/// overwritten anyway.
+///
+/// Only a prefix of the slice is written by a receive operation, namely as many elements as the
+/// message actually contained, which is given by `Status::count()`. Only those elements may be
+/// assumed to be initialized afterwards.
+///
+/// # Examples
+/// See `examples/receive_uninit.rs`
+unsafe impl<T> BufferMut for [MaybeUninit<T>] where T: Equivalence {}
+
+unsafe impl<T, const N: usize> AsDatatype for [T; N]
+where
+    T: Equivalence,
+{
+    type Out = <[T] as AsDatatype>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        self[..].as_datatype()
+    }
+}
+
+unsafe impl<T, const N: usize> Collection for [T; N]
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        self[..].count()
+    }
+}
+
+unsafe impl<T, const N: usize> Pointer for [T; N]
+where
+    T: Equivalence,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        self[..].pointer()
+    }
+}
+
+unsafe impl<T, const N: usize> PointerMut for [T; N]
+where
+    T: Equivalence,
+{
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self[..].pointer_mut()
+    }
+}
+
+/// Fixed-size arrays are buffers of `N` elements, just like the slice `&array[..]`, so that
+/// `&array` and `&mut array` can be passed to communication calls directly.
+///
+/// # Examples
+/// See `examples/fixed_array.rs`
+unsafe impl<T, const N: usize> Buffer for [T; N] where T: Equivalence {}
+unsafe impl<T, const N: usize> BufferMut for [T; N] where T: Equivalence {}
+
+/// An immutable dynamically-typed buffer.
+///
+/// The buffer has a definite length and MPI datatype, but it is not yet known which Rust type it
+/// corresponds to.  This is the MPI analogue of `&Any`.  It is semantically equivalent to the trait
+/// object reference `&Buffer`.
+#[derive(Copy, Clone, Debug)]
+pub struct DynBuffer<'a> {
+    ptr: *const c_void,
+    len: Count,
+    datatype: DatatypeRef<'a>,
+}
+
+unsafe impl<'a> Send for DynBuffer<'a> {}
+
+unsafe impl<'a> Sync for DynBuffer<'a> {}
+
+unsafe impl<'a> Collection for DynBuffer<'a> {
+    fn count(&self) -> Count {
+        self.len
+    }
+}
+
+unsafe impl<'a> Pointer for DynBuffer<'a> {
+    unsafe fn pointer(&self) -> *const c_void {
+        self.ptr
+    }
+}
+
+unsafe impl<'a> AsDatatype for DynBuffer<'a> {
+    type Out = DatatypeRef<'a>;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype
+    }
+}
+
+unsafe impl<'a> Buffer for DynBuffer<'a> {}
+
+impl<'a> DynBuffer<'a> {
+    /// Creates a buffer from a slice with whose type has an MPI equivalent.
+    pub fn new<T: Equivalence>(buf: &'a [T]) -> Self {
+        unsafe {
+            let datatype = DatatypeRef::from_raw_unchecked(T::equivalent_datatype().as_raw());
+            Self::from_raw(buf.as_ptr(), buf.count(), datatype)
+        }
+    }
+
+    /// Tests whether the buffer type matches `T`.
+    pub fn is<T: Equivalence>(&self) -> bool {
+        self.as_datatype().as_raw() == T::equivalent_datatype().as_raw()
+    }
+
+    /// Returns some slice if the type matches `T`, or `None` if it doesn't.
+    pub fn downcast<T: Equivalence>(self) -> Option<&'a [T]> {
+        if self.is::<T>() {
+            unsafe { Some(slice::from_raw_parts(self.as_ptr() as _, self.len())) }
+        } else {
+            None
+        }
+    }
+
+    /// Creates a buffer from its raw components.  The buffer must remain valid for `'a` and the
+    /// pointer must not be null.
+    pub unsafe fn from_raw<T>(ptr: *const T, len: Count, datatype: DatatypeRef<'a>) -> Self {
+        debug_assert!(!ptr.is_null());
+        Self {
+            ptr: ptr as _,
+            len,
+            datatype,
+        }
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.count()
+            .value_as()
+            .expect("Length of DynBuffer cannot be expressed as a usize")
+    }
+
+    /// Returns `true` if the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+}
+
+/// A mutable dynamically-typed buffer.
+///
+/// The buffer has a definite length and MPI datatype, but it is not yet known which Rust type it
+/// corresponds to.  This is the MPI analogue of `&mut Any`.  It is semantically equivalent to the
+/// mutable trait object reference `&mut BufferMut`.
+#[derive(Debug)]
+pub struct DynBufferMut<'a> {
+    ptr: *mut c_void,
+    len: Count,
+    datatype: DatatypeRef<'a>,
+}
+
+unsafe impl<'a> Send for DynBufferMut<'a> {}
+
+unsafe impl<'a> Sync for DynBufferMut<'a> {}
+
+unsafe impl<'a> Collection for DynBufferMut<'a> {
+    fn count(&self) -> Count {
+        self.len
+    }
+}
+
+unsafe impl<'a> Pointer for DynBufferMut<'a> {
+    unsafe fn pointer(&self) -> *const c_void {
+        self.ptr
+    }
+}
+
+unsafe impl<'a> PointerMut for DynBufferMut<'a> {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+unsafe impl<'a> Buffer for DynBufferMut<'a> {}
+
+unsafe impl<'a> BufferMut for DynBufferMut<'a> {}
+
+unsafe impl<'a> AsDatatype for DynBufferMut<'a> {
+    type Out = DatatypeRef<'a>;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype
+    }
+}
+
+impl<'a> DynBufferMut<'a> {
+    /// Creates a mutable buffer from a mutable slice with whose type has an MPI equivalent.
+    pub fn new<T: Equivalence>(buf: &'a mut [T]) -> Self {
+        unsafe {
+            let datatype = DatatypeRef::from_raw_unchecked(T::equivalent_datatype().as_raw());
+            Self::from_raw(buf.as_mut_ptr(), buf.count(), datatype)
+        }
+    }
+
+    /// Tests whether the buffer type matches `T`.
+    pub fn is<T: Equivalence>(&self) -> bool {
+        self.as_datatype().as_raw() == T::equivalent_datatype().as_raw()
+    }
+
+    /// Returns some mutable slice if the type matches `T`, or `None` if it doesn't.
+    pub fn downcast<T: Equivalence>(mut self) -> Option<&'a mut [T]> {
+        if self.is::<T>() {
+            unsafe {
+                Some(slice::from_raw_parts_mut(
+                    self.as_mut_ptr() as _,
+                    self.len(),
+                ))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Creates a buffer from its raw components.  The buffer must remain valid for `'a` and the
+    /// pointer must not be null.
+    pub unsafe fn from_raw<T>(ptr: *mut T, len: Count, datatype: DatatypeRef<'a>) -> Self {
+        debug_assert!(!ptr.is_null());
+        Self {
+            ptr: ptr as _,
+            len,
+            datatype,
+        }
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.count()
+            .value_as()
+            .expect("Length of DynBufferMut cannot be expressed as a usize")
+    }
+
+    /// Returns `true` if the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_mut_ptr(&mut self) -> *mut c_void {
+        self.ptr
+    }
+
+    /// Reborrows the buffer with a shorter lifetime.
+    pub fn reborrow(&self) -> DynBuffer {
+        unsafe { DynBuffer::from_raw(self.as_ptr(), self.count(), self.as_datatype()) }
+    }
+
+    /// Reborrows the buffer mutably with a shorter lifetime.
+    pub fn reborrow_mut(&mut self) -> DynBufferMut {
+        unsafe { DynBufferMut::from_raw(self.as_mut_ptr(), self.count(), self.as_datatype()) }
+    }
+
+    /// Makes the buffer immutable.
+    pub fn downgrade(self) -> DynBuffer<'a> {
+        unsafe { DynBuffer::from_raw(self.as_ptr(), self.count(), self.as_datatype()) }
+    }
+}
+
+/// A buffer with a user specified count and datatype
+///
+/// The datatype is only borrowed, so a single datatype can describe both a `View` that is sent
+/// and a `MutView` that is received into at the same time, without duplicating it.
+///
+/// # Examples
+/// See `examples/shared_datatype.rs`
+///
+/// # Safety
+///
+/// Views can be used to instruct the underlying MPI library to rummage around at arbitrary
+/// locations in memory. This might be controlled later on using datatype bounds an slice lengths
+/// but for now, all View constructors are marked `unsafe`, even the bounds checked
+/// `try_with_count_and_datatype()`. `SafeView` covers datatypes that are built from the element
+/// type of a slice without `unsafe`.
+pub struct View<'d, 'b, D, B: ?Sized>
+where
+    D: 'd + Datatype,
+    B: 'b + Pointer,
+{
+    datatype: &'d D,
+    count: Count,
+    buffer: &'b B,
+}
+
+impl<'d, 'b, D, B: ?Sized> View<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + Pointer,
+{
+    /// Return a view of `buffer` containing `count` instances of MPI datatype `datatype`.
+    ///
+    /// # Examples
+    /// See `examples/contiguous.rs`, `examples/vector.rs`
+    pub unsafe fn with_count_and_datatype(
+        buffer: &'b B,
+        count: Count,
+        datatype: &'d D,
+    ) -> View<'d, 'b, D, B> {
+        View {
+            datatype,
+            count,
+            buffer,
+        }
+    }
+}
+
+impl<'d, 'b, D, T> View<'d, 'b, D, [T]>
+where
+    D: 'd + Datatype,
+    [T]: 'b + Pointer,
+{
+    /// Return a view of `buffer` containing `count` instances of MPI datatype `datatype`, after
+    /// checking that they lie within `buffer`.
+    ///
+    /// Unlike `with_count_and_datatype()`, this uses the bounds of the datatype to ensure that
+    /// MPI only reads from the memory of `buffer`. Use `SafeView` for datatypes that are built
+    /// from elements of `T`, which need no `unsafe` at all.
+    ///
+    /// # Errors
+    ///
+    /// If any of the `count` instances of `datatype` would extend beyond either end of `buffer`.
+    ///
+    /// # Examples
+    /// See `examples/checked_view.rs`
+    ///
+    /// # Safety
+    ///
+    /// Within `buffer`, `datatype` may still describe arbitrary bytes, e.g. the padding of `T` or
+    /// parts of its fields, so it must only describe initialized bytes of `buffer` that are valid
+    /// for the predefined datatypes it consists of.
+    pub unsafe fn try_with_count_and_datatype(
+        buffer: &'b [T],
+        count: Count,
+        datatype: &'d D,
+    ) -> Result<View<'d, 'b, D, [T]>, OutOfBoundsError> {
+        check_bounds(datatype, count, size_of_val(buffer))?;
+        Ok(View {
+            datatype,
+            count,
+            buffer,
+        })
+    }
+
+    /// Narrows the view to the elements `range` of the underlying slice while keeping its
+    /// datatype.
+    ///
+    /// The count of the new view is the number of instances of the datatype that fit into the
+    /// bytes covered by `range`.
+    ///
+    /// # Examples
+    /// See `examples/sub_view.rs`
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds of the underlying slice or if it does not cover a whole number
+    /// of extents of the datatype.
+    ///
+    /// # Safety
+    ///
+    /// As for `with_count_and_datatype()`, the datatype must describe only memory within the
+    /// narrowed slice.
+    pub unsafe fn sub(&self, range: Range<usize>) -> View<'d, 'b, D, [T]> {
+        let buffer = &self.buffer[range];
+        let bytes = Address::try_from(size_of_val(buffer))
+            .expect("Narrowed view size cannot be expressed as an MPI Address.");
+        let extent = self.datatype.extent();
+        assert!(
+            extent > Address(0),
+            "Cannot narrow a view of a datatype without extent"
+        );
+        assert_eq!(
+            bytes.0 % extent.0,
+            0,
+            "'range' must cover a whole number of extents of the datatype"
+        );
+        View {
+            datatype: self.datatype,
+            count: (bytes.0 / extent.0)
+                .value_as()
+                .expect("Narrowed view count cannot be expressed as an MPI Count."),
+            buffer,
+        }
+    }
+}
+
+unsafe impl<'d, 'b, D, B: ?Sized> AsDatatype for View<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + Pointer,
+{
+    type Out = &'d D;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype
+    }
+}
+
+unsafe impl<'d, 'b, D, B: ?Sized> Collection for View<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + Pointer,
+{
+    fn count(&self) -> Count {
+        self.count
+    }
+}
+
+unsafe impl<'d, 'b, D, B: ?Sized> Pointer for View<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + Pointer,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        self.buffer.pointer()
+    }
+}
+
+unsafe impl<'d, 'b, D, B: ?Sized> Buffer for View<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + Pointer,
+{
+}
+
+/// A buffer of `count` instances of a datatype built from the elements of a slice
+///
+/// Unlike `View`, this is constructed without `unsafe`. The element type of the datatype is known
+/// at the type level to be `T`, see `TypedDatatype`, and the bounds of the datatype are checked
+/// against the slice on construction, so MPI only ever reads elements of `T` that lie within it.
+///
+/// A datatype built from a different element type does not compile:
+///
+/// ```compile_fail
+/// # use mpi::datatype::{SafeView, TypedDatatype};
+/// # let _universe = mpi::initialize().unwrap();
+/// let values = [1i32, 2, 3, 4];
+/// let pairs = TypedDatatype::<f64>::contiguous(2);
+/// let view = SafeView::new(&values[..], 1, &pairs);
+/// ```
+///
+/// # Examples
+/// See `examples/safe_view.rs`
+pub struct SafeView<'a, T> {
+    datatype: &'a TypedDatatype<T>,
+    count: Count,
+    buffer: &'a [T],
+}
+
+impl<'a, T> SafeView<'a, T>
+where
+    T: Equivalence,
+{
+    /// Return a view of `buffer` containing `count` instances of `datatype`, after checking that
+    /// they lie within `buffer`.
+    ///
+    /// # Errors
+    ///
+    /// If any of the `count` instances of `datatype` would extend beyond either end of `buffer`.
+    pub fn new(
+        buffer: &'a [T],
+        count: Count,
+        datatype: &'a TypedDatatype<T>,
+    ) -> Result<SafeView<'a, T>, OutOfBoundsError> {
+        check_bounds(datatype, count, size_of_val(buffer))?;
+        Ok(SafeView {
+            datatype,
+            count,
+            buffer,
+        })
+    }
+}
+
+unsafe impl<'a, T> AsDatatype for SafeView<'a, T> {
+    type Out = &'a TypedDatatype<T>;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype
+    }
+}
+
+unsafe impl<'a, T> Collection for SafeView<'a, T> {
+    fn count(&self) -> Count {
+        self.count
+    }
+}
+
+unsafe impl<'a, T> Pointer for SafeView<'a, T>
+where
+    T: Equivalence,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        self.buffer.pointer()
+    }
+}
+
+unsafe impl<'a, T> Buffer for SafeView<'a, T> where T: Equivalence {}
+
+/// A buffer with a user specified count and datatype
+///
+/// The datatype is only borrowed, so a single datatype can describe both a `View` that is sent
+/// and a `MutView` that is received into at the same time, without duplicating it.
+///
+/// # Examples
+/// See `examples/shared_datatype.rs`
+///
+/// # Safety
+///
+/// Views can be used to instruct the underlying MPI library to rummage around at arbitrary
+/// locations in memory. This might be controlled later on using datatype bounds an slice lengths
+/// but for now, all View constructors are marked `unsafe`.
+pub struct MutView<'d, 'b, D, B: ?Sized>
+where
+    D: 'd + Datatype,
+    B: 'b + PointerMut,
+{
+    datatype: &'d D,
+    count: Count,
+    buffer: &'b mut B,
+}
+
+impl<'d, 'b, D, B: ?Sized> MutView<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + PointerMut,
+{
+    /// Return a view of `buffer` containing `count` instances of MPI datatype `datatype`.
+    ///
+    /// # Examples
+    /// See `examples/contiguous.rs`, `examples/vector.rs`
+    pub unsafe fn with_count_and_datatype(
+        buffer: &'b mut B,
+        count: Count,
+        datatype: &'d D,
+    ) -> MutView<'d, 'b, D, B> {
+        MutView {
+            datatype,
+            count,
+            buffer,
         }
     }
+}
 
-    /// Commits a datatype to a specific representation so that it can be used in MPI calls.
+unsafe impl<'d, 'b, D, B: ?Sized> AsDatatype for MutView<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + PointerMut,
+{
+    type Out = &'d D;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype
+    }
+}
+
+unsafe impl<'d, 'b, D, B: ?Sized> Collection for MutView<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + PointerMut,
+{
+    fn count(&self) -> Count {
+        self.count
+    }
+}
+
+unsafe impl<'d, 'b, D, B: ?Sized> PointerMut for MutView<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + PointerMut,
+{
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.buffer.pointer_mut()
+    }
+}
+
+unsafe impl<'d, 'b, D, B: ?Sized> BufferMut for MutView<'d, 'b, D, B>
+where
+    D: 'd + Datatype,
+    B: 'b + PointerMut,
+{
+}
+
+/// A buffer with a user specified count and a datatype that is committed on first use
+///
+/// Unlike `View`, this borrows a `LazyDatatype`, which is only committed once the view is passed
+/// to a communication operation, e.g. a send, and MPI asks for its datatype.
+///
+/// # Examples
+/// See `examples/lazy_datatype.rs`
+///
+/// # Safety
+///
+/// As for `View`, the datatype is not checked against the buffer.
+pub struct LazyView<'d, 'b, B: ?Sized>
+where
+    B: 'b + Pointer,
+{
+    datatype: &'d LazyDatatype,
+    count: Count,
+    buffer: &'b B,
+}
+
+impl<'d, 'b, B: ?Sized> LazyView<'d, 'b, B>
+where
+    B: 'b + Pointer,
+{
+    /// Return a view of `buffer` containing `count` instances of `datatype`, without committing
+    /// it yet.
     ///
-    /// # Standard section(s)
-    /// 4.1.9
-    pub fn commit(mut self) -> UserDatatype {
-        let handle = self.0;
-        unsafe {
-            ffi::MPI_Type_commit(&mut self.0);
+    /// # Examples
+    /// See `examples/lazy_datatype.rs`
+    ///
+    /// # Safety
+    ///
+    /// `count` instances of `datatype` must only describe memory within `buffer`.
+    pub unsafe fn with_count_and_datatype(
+        buffer: &'b B,
+        count: Count,
+        datatype: &'d LazyDatatype,
+    ) -> LazyView<'d, 'b, B> {
+        LazyView {
+            datatype,
+            count,
+            buffer,
         }
-        mem::forget(self);
-        UserDatatype(handle)
     }
+}
 
-    /// Creates an UncommittedDatatypeRef from this datatype object.
-    pub fn as_ref(&self) -> UncommittedDatatypeRef<'_> {
-        unsafe { UncommittedDatatypeRef::from_raw(self.as_raw()) }
+unsafe impl<'d, 'b, B: ?Sized> AsDatatype for LazyView<'d, 'b, B>
+where
+    B: 'b + Pointer,
+{
+    type Out = &'d UserDatatype;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype.committed()
     }
 }
 
-impl Clone for UncommittedUserDatatype {
-    fn clone(&self) -> Self {
-        self.dup()
+unsafe impl<'d, 'b, B: ?Sized> Collection for LazyView<'d, 'b, B>
+where
+    B: 'b + Pointer,
+{
+    fn count(&self) -> Count {
+        self.count
     }
 }
 
-impl Drop for UncommittedUserDatatype {
-    fn drop(&mut self) {
-        unsafe {
-            ffi::MPI_Type_free(&mut self.0);
+unsafe impl<'d, 'b, B: ?Sized> Pointer for LazyView<'d, 'b, B>
+where
+    B: 'b + Pointer,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        self.buffer.pointer()
+    }
+}
+
+unsafe impl<'d, 'b, B: ?Sized> Buffer for LazyView<'d, 'b, B> where B: 'b + Pointer {}
+
+/// A mutable buffer with a user specified count and a datatype that is committed on first use
+///
+/// Like `LazyView`, but for receiving, e.g. via `Source::receive_into()`.
+///
+/// # Examples
+/// See `examples/lazy_datatype.rs`
+///
+/// # Safety
+///
+/// As for `MutView`, the datatype is not checked against the buffer.
+pub struct LazyMutView<'d, 'b, B: ?Sized>
+where
+    B: 'b + PointerMut,
+{
+    datatype: &'d LazyDatatype,
+    count: Count,
+    buffer: &'b mut B,
+}
+
+impl<'d, 'b, B: ?Sized> LazyMutView<'d, 'b, B>
+where
+    B: 'b + PointerMut,
+{
+    /// Return a view of `buffer` containing `count` instances of `datatype`, without committing
+    /// it yet.
+    ///
+    /// # Examples
+    /// See `examples/lazy_datatype.rs`
+    ///
+    /// # Safety
+    ///
+    /// `count` instances of `datatype` must only describe memory within `buffer`.
+    pub unsafe fn with_count_and_datatype(
+        buffer: &'b mut B,
+        count: Count,
+        datatype: &'d LazyDatatype,
+    ) -> LazyMutView<'d, 'b, B> {
+        LazyMutView {
+            datatype,
+            count,
+            buffer,
         }
-        assert_eq!(self.0, unsafe_extern_static!(ffi::RSMPI_DATATYPE_NULL));
     }
 }
 
-unsafe impl AsRaw for UncommittedUserDatatype {
-    type Raw = MPI_Datatype;
-    fn as_raw(&self) -> Self::Raw {
-        self.0
+unsafe impl<'d, 'b, B: ?Sized> AsDatatype for LazyMutView<'d, 'b, B>
+where
+    B: 'b + PointerMut,
+{
+    type Out = &'d UserDatatype;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype.committed()
     }
 }
 
-impl FromRaw for UncommittedUserDatatype {
-    unsafe fn from_raw(handle: MPI_Datatype) -> Self {
-        assert_ne!(handle, ffi::RSMPI_DATATYPE_NULL);
-        UncommittedUserDatatype(handle)
+unsafe impl<'d, 'b, B: ?Sized> Collection for LazyMutView<'d, 'b, B>
+where
+    B: 'b + PointerMut,
+{
+    fn count(&self) -> Count {
+        self.count
     }
 }
 
-unsafe impl MatchesRaw for UncommittedUserDatatype {}
-
-impl UncommittedDatatype for UncommittedUserDatatype {
-    type DuplicatedDatatype = UncommittedUserDatatype;
-}
-
-impl<'a> From<&'a UncommittedUserDatatype> for UncommittedDatatypeRef<'a> {
-    fn from(datatype: &'a UncommittedUserDatatype) -> Self {
-        unsafe { UncommittedDatatypeRef::from_raw(datatype.as_raw()) }
+unsafe impl<'d, 'b, B: ?Sized> PointerMut for LazyMutView<'d, 'b, B>
+where
+    B: 'b + PointerMut,
+{
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.buffer.pointer_mut()
     }
 }
 
-/// A Datatype describes the layout of messages in memory.
-///
-/// `Datatype` always represents a committed datatype that can be immediately used for sending and
-/// receiving messages. `UncommittedDatatype` is used for datatypes that are possibly uncommitted.
-pub trait Datatype: UncommittedDatatype {}
-impl<'a, D> Datatype for &'a D where D: 'a + Datatype {}
+unsafe impl<'d, 'b, B: ?Sized> BufferMut for LazyMutView<'d, 'b, B> where B: 'b + PointerMut {}
 
-/// An UncommittedDatatype is a partial description of the layout of messages in memory which may
-/// not yet have been committed to an implementation-defined message format.
+/// A buffer in the memory of a GPU with a user specified count and datatype
 ///
-/// Committed datatypes can be treated as-if they are uncommitted.
-pub trait UncommittedDatatype: AsRaw<Raw = MPI_Datatype> {
-    /// The type returned when the datatype is duplicated.
-    type DuplicatedDatatype: FromRaw<Raw = MPI_Datatype>;
+/// The device pointer is handed to MPI as is and never dereferenced on the host, so this requires
+/// an MPI library that is CUDA-aware, i.e. accepts device pointers in communication calls. Only
+/// available with the `cuda` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use mpi::datatype::{DeviceView, UserDatatype};
+/// use mpi::traits::*;
+///
+/// // `device_ptr` points to `2 * n` doubles allocated on the GPU, e.g. by `cudaMalloc()`.
+/// fn send_every_other(device_ptr: *const f64, n: mpi::Count) {
+///     let universe = mpi::initialize().unwrap();
+///     let world = universe.world();
+///
+///     let t = UserDatatype::vector(n, 1, 2, &f64::equivalent_datatype());
+///     let view = unsafe { DeviceView::from_raw(device_ptr, 1, &t) };
+///     world.process_at_rank(1).send(&view);
+/// }
+/// ```
+///
+/// # Safety
+///
+/// See `View`. Additionally, the pointer must point to device memory that holds `count`
+/// instances of the datatype for as long as the buffer is in use.
+#[cfg(feature = "cuda")]
+pub struct DeviceView<'d, D>
+where
+    D: 'd + Datatype,
+{
+    datatype: &'d D,
+    count: Count,
+    pointer: *const c_void,
+}
 
-    /// Creates a new datatype with the same key-values as this datatype.
+#[cfg(feature = "cuda")]
+impl<'d, D> DeviceView<'d, D>
+where
+    D: 'd + Datatype,
+{
+    /// Return a view of the device memory at `pointer` containing `count` instances of MPI
+    /// datatype `datatype`.
     ///
-    /// # Standard section(s)
-    /// 4.1.10
-    fn dup(&self) -> Self::DuplicatedDatatype {
-        unsafe {
-            Self::DuplicatedDatatype::from_raw(
-                with_uninitialized(|newtype| ffi::MPI_Type_dup(self.as_raw(), newtype)).1,
-            )
+    /// # Safety
+    /// See the documentation of `DeviceView`.
+    pub unsafe fn from_raw<T>(pointer: *const T, count: Count, datatype: &'d D) -> Self {
+        DeviceView {
+            datatype,
+            count,
+            pointer: pointer as *const c_void,
         }
     }
 }
-impl<'a, D> UncommittedDatatype for &'a D
+
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> AsDatatype for DeviceView<'d, D>
 where
-    D: 'a + UncommittedDatatype,
+    D: 'd + Datatype,
 {
-    type DuplicatedDatatype = <D as UncommittedDatatype>::DuplicatedDatatype;
-}
-
-/// Something that has an associated datatype
-pub unsafe trait AsDatatype {
-    /// The type of the associated MPI datatype (e.g. `SystemDatatype` or `UserDatatype`)
-    type Out: Datatype;
-    /// The associated MPI datatype
-    fn as_datatype(&self) -> Self::Out;
+    type Out = &'d D;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype
+    }
 }
 
-unsafe impl<T> AsDatatype for T
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> Collection for DeviceView<'d, D>
 where
-    T: Equivalence,
+    D: 'd + Datatype,
 {
-    type Out = <T as Equivalence>::Out;
-    fn as_datatype(&self) -> Self::Out {
-        <T as Equivalence>::equivalent_datatype()
+    fn count(&self) -> Count {
+        self.count
     }
 }
 
-unsafe impl<T> AsDatatype for [T]
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> Pointer for DeviceView<'d, D>
 where
-    T: Equivalence,
+    D: 'd + Datatype,
 {
-    type Out = <T as Equivalence>::Out;
-    fn as_datatype(&self) -> Self::Out {
-        <T as Equivalence>::equivalent_datatype()
+    unsafe fn pointer(&self) -> *const c_void {
+        self.pointer
     }
 }
 
-#[doc(hidden)]
-pub mod internal {
-    #[cfg(feature = "derive")]
-    pub fn check_derive_equivalence_universe_state(type_name: &str) {
-        use crate::environment::UNIVERSE_STATE;
-
-        // Take lock on UNIVERSE_STATE to prevent racing with mpi::init and mpi::finalize.
-        let universe_state = UNIVERSE_STATE.read().unwrap();
-
-        if !crate::environment::is_initialized() {
-            panic!(
-                "\n\
-                 RSMPI PANIC: Pre-MPI_Init datatype initialization\n\
-                 \n\
-                 Application attempted to initialize datatype of #[derive(Equivalence)] for \
-                 `{}` before initializing rsmpi. You must first initialize rsmpi before attempting \
-                 to use a custom type in an MPI call.\n",
-                type_name
-            );
-        }
-
-        let universe_state = universe_state.as_ref().unwrap();
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> Buffer for DeviceView<'d, D> where D: 'd + Datatype {}
 
-        if crate::environment::is_finalized() {
-            panic!(
-                "\n\
-                 RSMPI PANIC: Post-MPI_Finalize datatype initialization.\n\
-                 \n\
-                 Application attempted to initialize datatype of #[derive(Equivalence)] for \
-                 `{0}` after finalizing rsmpi. You must not attempt to use `{0}` in an MPI context \
-                 after finalizing rsmpi.\n",
-                type_name
-            );
-        }
+/// A mutable buffer in the memory of a GPU with a user specified count and datatype
+///
+/// See `DeviceView`. Only available with the `cuda` feature.
+///
+/// # Safety
+///
+/// See `DeviceView`. Additionally, the device memory must be valid for writes and not be accessed
+/// otherwise while the buffer is in use.
+#[cfg(feature = "cuda")]
+pub struct DeviceMutView<'d, D>
+where
+    D: 'd + Datatype,
+{
+    datatype: &'d D,
+    count: Count,
+    pointer: *mut c_void,
+}
 
-        if crate::environment::threading_support() != crate::Threading::Multiple {
-            if universe_state.main_thread != std::thread::current().id() {
-                panic!(
-                    "\n\
-                     RSMPI PANIC: Invalid threaded datatype initialization\n\
-                     \n\
-                     Application attempted to initialize the datatype of #[derive(Equivalence)]
-                     for `{0}` from a different thread than that which initialized `rsmpi`. This \
-                     is only supported when rsmpi is initialized with \
-                     `mpi::Threading::Multiple`. Please explicitly call \
-                     `{0}::equivalent_datatype()` at least once from the same thread as you call \
-                     `rsmpi::initialize*`, or initialize MPI using \
-                     `mpi::initialize_with_threading(mpi::Threading::Multiple)`.\n",
-                    type_name
-                )
-            }
+#[cfg(feature = "cuda")]
+impl<'d, D> DeviceMutView<'d, D>
+where
+    D: 'd + Datatype,
+{
+    /// Return a view of the device memory at `pointer` containing `count` instances of MPI
+    /// datatype `datatype`.
+    ///
+    /// # Safety
+    /// See the documentation of `DeviceMutView`.
+    pub unsafe fn from_raw<T>(pointer: *mut T, count: Count, datatype: &'d D) -> Self {
+        DeviceMutView {
+            datatype,
+            count,
+            pointer: pointer as *mut c_void,
         }
     }
 }
 
-/// A countable collection of things.
-pub unsafe trait Collection {
-    /// How many things are in this collection.
-    fn count(&self) -> Count;
-}
-
-unsafe impl<T> Collection for T
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> AsDatatype for DeviceMutView<'d, D>
 where
-    T: Equivalence,
+    D: 'd + Datatype,
 {
-    fn count(&self) -> Count {
-        1
+    type Out = &'d D;
+    fn as_datatype(&self) -> Self::Out {
+        self.datatype
     }
 }
 
-unsafe impl<T> Collection for [T]
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> Collection for DeviceMutView<'d, D>
 where
-    T: Equivalence,
+    D: 'd + Datatype,
 {
     fn count(&self) -> Count {
-        self.len()
-            .value_as()
-            .expect("Length of slice cannot be expressed as an MPI Count.")
+        self.count
     }
 }
 
-/// Provides a pointer to the starting address in memory.
-pub unsafe trait Pointer {
-    /// A pointer to the starting address in memory
-    unsafe fn pointer(&self) -> *const c_void;
-}
-
-unsafe impl<T> Pointer for T
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> PointerMut for DeviceMutView<'d, D>
 where
-    T: Equivalence,
+    D: 'd + Datatype,
 {
-    unsafe fn pointer(&self) -> *const c_void {
-        let p: *const T = self;
-        p as *const c_void
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.pointer
     }
 }
 
-unsafe impl<T> Pointer for [T]
+#[cfg(feature = "cuda")]
+unsafe impl<'d, D> BufferMut for DeviceMutView<'d, D> where D: 'd + Datatype {}
+
+/// The predefined datatype and count that `count` instances of `datatype` amount to, if
+/// `datatype` is built from `contiguous()` alone
+fn contiguous_base<D>(datatype: &D, count: Count) -> Option<(SystemDatatype, Count)>
 where
-    T: Equivalence,
+    D: ?Sized + UncommittedDatatype,
 {
-    unsafe fn pointer(&self) -> *const c_void {
-        self.as_ptr() as _
+    let contents = datatype.contents()?;
+    let count = match contents.arguments()? {
+        Arguments::Contiguous { count: n } => count.checked_mul(n)?,
+        _ => return None,
+    };
+    match contents.datatypes.into_iter().next()? {
+        DecodedDatatype::Predefined(base) => Some((base, count)),
+        derived => contiguous_base(&derived, count),
     }
 }
 
-/// Provides a mutable pointer to the starting address in memory.
-pub unsafe trait PointerMut {
-    /// A mutable pointer to the starting address in memory
-    unsafe fn pointer_mut(&mut self) -> *mut c_void;
+/// The datatype of a `Simplified` or `SimplifiedMut` buffer
+#[derive(Copy, Clone, Debug)]
+pub enum SimplifiedDatatype<D> {
+    /// The predefined datatype the original datatype was built from
+    Base(SystemDatatype),
+    /// The original datatype, as it is not built from `contiguous()` alone
+    Original(D),
 }
 
-unsafe impl<T> PointerMut for T
+unsafe impl<D> AsRaw for SimplifiedDatatype<D>
 where
-    T: Equivalence,
+    D: Datatype,
 {
-    unsafe fn pointer_mut(&mut self) -> *mut c_void {
-        let p: *mut T = self;
-        p as *mut c_void
+    type Raw = MPI_Datatype;
+    fn as_raw(&self) -> Self::Raw {
+        match *self {
+            SimplifiedDatatype::Base(ref datatype) => datatype.as_raw(),
+            SimplifiedDatatype::Original(ref datatype) => datatype.as_raw(),
+        }
     }
 }
 
-unsafe impl<T> PointerMut for [T]
+impl<D> UncommittedDatatype for SimplifiedDatatype<D>
 where
-    T: Equivalence,
+    D: Datatype,
 {
-    unsafe fn pointer_mut(&mut self) -> *mut c_void {
-        self.as_mut_ptr() as _
-    }
+    type DuplicatedDatatype = UserDatatype;
 }
 
-/// A buffer is a region in memory that starts at `pointer()` and contains `count()` copies of
-/// `as_datatype()`.
-pub unsafe trait Buffer: Pointer + Collection + AsDatatype {}
-unsafe impl<T> Buffer for T where T: Equivalence {}
-unsafe impl<T> Buffer for [T] where T: Equivalence {}
-
-/// A mutable buffer is a region in memory that starts at `pointer_mut()` and contains `count()`
-/// copies of `as_datatype()`.
-pub unsafe trait BufferMut: PointerMut + Collection + AsDatatype {}
-unsafe impl<T> BufferMut for T where T: Equivalence {}
-unsafe impl<T> BufferMut for [T] where T: Equivalence {}
+impl<D> Datatype for SimplifiedDatatype<D> where D: Datatype {}
 
-/// An immutable dynamically-typed buffer.
+/// A buffer whose datatype is replaced by the predefined datatype it is built from, if it is built
+/// from `contiguous()` alone.
 ///
-/// The buffer has a definite length and MPI datatype, but it is not yet known which Rust type it
-/// corresponds to.  This is the MPI analogue of `&Any`.  It is semantically equivalent to the trait
-/// object reference `&Buffer`.
-#[derive(Copy, Clone, Debug)]
-pub struct DynBuffer<'a> {
-    ptr: *const c_void,
-    len: Count,
-    datatype: DatatypeRef<'a>,
-}
-
-unsafe impl<'a> Send for DynBuffer<'a> {}
-
-unsafe impl<'a> Sync for DynBuffer<'a> {}
+/// `count` instances of `contiguous(n, base)` describe the same data as `count * n` instances of
+/// `base`, e.g. `f64`, which are communicated instead. Nested contiguous datatypes are simplified
+/// as well. Buffers with any other datatype, or whose simplified count would exceed the range of
+/// `Count`, are passed through unchanged.
+///
+/// The datatype is decoded via `MPI_Type_get_envelope()` and `MPI_Type_get_contents()` once, when
+/// the buffer is wrapped.
+///
+/// # Examples
+/// See `examples/simplified.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2, 4.1.13
+pub struct Simplified<'b, B: ?Sized>
+where
+    B: 'b + Buffer,
+{
+    buffer: &'b B,
+    base: Option<(SystemDatatype, Count)>,
+}
 
-unsafe impl<'a> Collection for DynBuffer<'a> {
-    fn count(&self) -> Count {
-        self.len
+impl<'b, B: ?Sized> Simplified<'b, B>
+where
+    B: 'b + Buffer,
+{
+    /// Simplifies the datatype of `buffer`.
+    pub fn new(buffer: &'b B) -> Self {
+        let base = contiguous_base(&buffer.as_datatype(), buffer.count());
+        Simplified { buffer, base }
     }
-}
 
-unsafe impl<'a> Pointer for DynBuffer<'a> {
-    unsafe fn pointer(&self) -> *const c_void {
-        self.ptr
+    /// Whether the datatype was replaced by a predefined datatype
+    pub fn is_simplified(&self) -> bool {
+        self.base.is_some()
     }
 }
 
-unsafe impl<'a> AsDatatype for DynBuffer<'a> {
-    type Out = DatatypeRef<'a>;
+unsafe impl<'b, B: ?Sized> AsDatatype for Simplified<'b, B>
+where
+    B: 'b + Buffer,
+{
+    type Out = SimplifiedDatatype<B::Out>;
     fn as_datatype(&self) -> Self::Out {
-        self.datatype
+        match self.base {
+            Some((base, _)) => SimplifiedDatatype::Base(base),
+            None => SimplifiedDatatype::Original(self.buffer.as_datatype()),
+        }
     }
 }
 
-unsafe impl<'a> Buffer for DynBuffer<'a> {}
-
-impl<'a> DynBuffer<'a> {
-    /// Creates a buffer from a slice with whose type has an MPI equivalent.
-    pub fn new<T: Equivalence>(buf: &'a [T]) -> Self {
-        unsafe {
-            let datatype = DatatypeRef::from_raw(T::equivalent_datatype().as_raw());
-            Self::from_raw(buf.as_ptr(), buf.count(), datatype)
+unsafe impl<'b, B: ?Sized> Collection for Simplified<'b, B>
+where
+    B: 'b + Buffer,
+{
+    fn count(&self) -> Count {
+        match self.base {
+            Some((_, count)) => count,
+            None => self.buffer.count(),
         }
     }
+}
 
-    /// Tests whether the buffer type matches `T`.
-    pub fn is<T: Equivalence>(&self) -> bool {
-        self.as_datatype().as_raw() == T::equivalent_datatype().as_raw()
-    }
-
-    /// Returns some slice if the type matches `T`, or `None` if it doesn't.
-    pub fn downcast<T: Equivalence>(self) -> Option<&'a [T]> {
-        if self.is::<T>() {
-            unsafe { Some(slice::from_raw_parts(self.as_ptr() as _, self.len())) }
-        } else {
-            None
-        }
+unsafe impl<'b, B: ?Sized> Pointer for Simplified<'b, B>
+where
+    B: 'b + Buffer,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        self.buffer.pointer()
     }
+}
 
-    /// Creates a buffer from its raw components.  The buffer must remain valid for `'a` and the
-    /// pointer must not be null.
-    pub unsafe fn from_raw<T>(ptr: *const T, len: Count, datatype: DatatypeRef<'a>) -> Self {
-        debug_assert!(!ptr.is_null());
-        Self {
-            ptr: ptr as _,
-            len,
-            datatype,
-        }
-    }
+unsafe impl<'b, B: ?Sized> Buffer for Simplified<'b, B> where B: 'b + Buffer {}
 
-    /// Returns the number of elements in the buffer.
-    pub fn len(&self) -> usize {
-        self.count()
-            .value_as()
-            .expect("Length of DynBuffer cannot be expressed as a usize")
-    }
+/// A mutable buffer whose datatype is replaced by the predefined datatype it is built from, if it
+/// is built from `contiguous()` alone, see `Simplified`.
+///
+/// # Examples
+/// See `examples/simplified.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2, 4.1.13
+pub struct SimplifiedMut<'b, B: ?Sized>
+where
+    B: 'b + BufferMut,
+{
+    buffer: &'b mut B,
+    base: Option<(SystemDatatype, Count)>,
+}
 
-    /// Returns `true` if the buffer is empty
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+impl<'b, B: ?Sized> SimplifiedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
+    /// Simplifies the datatype of `buffer`.
+    pub fn new(buffer: &'b mut B) -> Self {
+        let base = contiguous_base(&buffer.as_datatype(), buffer.count());
+        SimplifiedMut { buffer, base }
     }
 
-    /// Returns the underlying raw pointer.
-    pub fn as_ptr(&self) -> *const c_void {
-        self.ptr
+    /// Whether the datatype was replaced by a predefined datatype
+    pub fn is_simplified(&self) -> bool {
+        self.base.is_some()
     }
 }
 
-/// A mutable dynamically-typed buffer.
-///
-/// The buffer has a definite length and MPI datatype, but it is not yet known which Rust type it
-/// corresponds to.  This is the MPI analogue of `&mut Any`.  It is semantically equivalent to the
-/// mutable trait object reference `&mut BufferMut`.
-#[derive(Debug)]
-pub struct DynBufferMut<'a> {
-    ptr: *mut c_void,
-    len: Count,
-    datatype: DatatypeRef<'a>,
-}
-
-unsafe impl<'a> Send for DynBufferMut<'a> {}
-
-unsafe impl<'a> Sync for DynBufferMut<'a> {}
-
-unsafe impl<'a> Collection for DynBufferMut<'a> {
-    fn count(&self) -> Count {
-        self.len
+unsafe impl<'b, B: ?Sized> AsDatatype for SimplifiedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
+    type Out = SimplifiedDatatype<B::Out>;
+    fn as_datatype(&self) -> Self::Out {
+        match self.base {
+            Some((base, _)) => SimplifiedDatatype::Base(base),
+            None => SimplifiedDatatype::Original(self.buffer.as_datatype()),
+        }
     }
 }
 
-unsafe impl<'a> Pointer for DynBufferMut<'a> {
-    unsafe fn pointer(&self) -> *const c_void {
-        self.ptr
+unsafe impl<'b, B: ?Sized> Collection for SimplifiedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
+    fn count(&self) -> Count {
+        match self.base {
+            Some((_, count)) => count,
+            None => self.buffer.count(),
+        }
     }
 }
 
-unsafe impl<'a> PointerMut for DynBufferMut<'a> {
+unsafe impl<'b, B: ?Sized> PointerMut for SimplifiedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
     unsafe fn pointer_mut(&mut self) -> *mut c_void {
-        self.ptr
+        self.buffer.pointer_mut()
     }
 }
 
-unsafe impl<'a> Buffer for DynBufferMut<'a> {}
-
-unsafe impl<'a> BufferMut for DynBufferMut<'a> {}
+unsafe impl<'b, B: ?Sized> BufferMut for SimplifiedMut<'b, B> where B: 'b + BufferMut {}
 
-unsafe impl<'a> AsDatatype for DynBufferMut<'a> {
-    type Out = DatatypeRef<'a>;
-    fn as_datatype(&self) -> Self::Out {
-        self.datatype
-    }
+/// A buffer that only contains the first `count()` elements of another buffer, see
+/// `Buffer::truncated()`.
+///
+/// # Examples
+/// See `examples/truncated.rs`
+pub struct Truncated<'b, B: ?Sized>
+where
+    B: 'b + Buffer,
+{
+    buffer: &'b B,
+    count: Count,
 }
 
-impl<'a> DynBufferMut<'a> {
-    /// Creates a mutable buffer from a mutable slice with whose type has an MPI equivalent.
-    pub fn new<T: Equivalence>(buf: &'a mut [T]) -> Self {
-        unsafe {
-            let datatype = DatatypeRef::from_raw(T::equivalent_datatype().as_raw());
-            Self::from_raw(buf.as_mut_ptr(), buf.count(), datatype)
-        }
-    }
-
-    /// Tests whether the buffer type matches `T`.
-    pub fn is<T: Equivalence>(&self) -> bool {
-        self.as_datatype().as_raw() == T::equivalent_datatype().as_raw()
+impl<'b, B: ?Sized> Truncated<'b, B>
+where
+    B: 'b + Buffer,
+{
+    /// Restricts `buffer` to its first `n` elements.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is negative or larger than `buffer.count()`.
+    pub fn new(buffer: &'b B, n: Count) -> Self {
+        assert!(
+            0 <= n && n <= buffer.count(),
+            "'n' must be between 0 and the count of the buffer"
+        );
+        Truncated { buffer, count: n }
     }
+}
 
-    /// Returns some mutable slice if the type matches `T`, or `None` if it doesn't.
-    pub fn downcast<T: Equivalence>(mut self) -> Option<&'a mut [T]> {
-        if self.is::<T>() {
-            unsafe {
-                Some(slice::from_raw_parts_mut(
-                    self.as_mut_ptr() as _,
-                    self.len(),
-                ))
-            }
-        } else {
-            None
-        }
+unsafe impl<'b, B: ?Sized> AsDatatype for Truncated<'b, B>
+where
+    B: 'b + Buffer,
+{
+    type Out = B::Out;
+    fn as_datatype(&self) -> Self::Out {
+        self.buffer.as_datatype()
     }
+}
 
-    /// Creates a buffer from its raw components.  The buffer must remain valid for `'a` and the
-    /// pointer must not be null.
-    pub unsafe fn from_raw<T>(ptr: *mut T, len: Count, datatype: DatatypeRef<'a>) -> Self {
-        debug_assert!(!ptr.is_null());
-        Self {
-            ptr: ptr as _,
-            len,
-            datatype,
-        }
+unsafe impl<'b, B: ?Sized> Collection for Truncated<'b, B>
+where
+    B: 'b + Buffer,
+{
+    fn count(&self) -> Count {
+        self.count
     }
+}
 
-    /// Returns the number of elements in the buffer.
-    pub fn len(&self) -> usize {
-        self.count()
-            .value_as()
-            .expect("Length of DynBufferMut cannot be expressed as a usize")
+unsafe impl<'b, B: ?Sized> Pointer for Truncated<'b, B>
+where
+    B: 'b + Buffer,
+{
+    unsafe fn pointer(&self) -> *const c_void {
+        self.buffer.pointer()
     }
+}
 
-    /// Returns `true` if the buffer is empty
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
+unsafe impl<'b, B: ?Sized> Buffer for Truncated<'b, B> where B: 'b + Buffer {}
 
-    /// Returns the underlying raw pointer.
-    pub fn as_ptr(&self) -> *const c_void {
-        self.ptr
-    }
+/// A mutable buffer that only contains the first `count()` elements of another buffer, see
+/// `BufferMut::truncated_mut()`.
+///
+/// # Examples
+/// See `examples/truncated.rs`
+pub struct TruncatedMut<'b, B: ?Sized>
+where
+    B: 'b + BufferMut,
+{
+    buffer: &'b mut B,
+    count: Count,
+}
 
-    /// Returns the underlying raw pointer.
-    pub fn as_mut_ptr(&mut self) -> *mut c_void {
-        self.ptr
+impl<'b, B: ?Sized> TruncatedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
+    /// Restricts `buffer` to its first `n` elements.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is negative or larger than `buffer.count()`.
+    pub fn new(buffer: &'b mut B, n: Count) -> Self {
+        assert!(
+            0 <= n && n <= buffer.count(),
+            "'n' must be between 0 and the count of the buffer"
+        );
+        TruncatedMut { buffer, count: n }
     }
+}
 
-    /// Reborrows the buffer with a shorter lifetime.
-    pub fn reborrow(&self) -> DynBuffer {
-        unsafe { DynBuffer::from_raw(self.as_ptr(), self.count(), self.as_datatype()) }
+unsafe impl<'b, B: ?Sized> AsDatatype for TruncatedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
+    type Out = B::Out;
+    fn as_datatype(&self) -> Self::Out {
+        self.buffer.as_datatype()
     }
+}
 
-    /// Reborrows the buffer mutably with a shorter lifetime.
-    pub fn reborrow_mut(&mut self) -> DynBufferMut {
-        unsafe { DynBufferMut::from_raw(self.as_mut_ptr(), self.count(), self.as_datatype()) }
+unsafe impl<'b, B: ?Sized> Collection for TruncatedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
+    fn count(&self) -> Count {
+        self.count
     }
+}
 
-    /// Makes the buffer immutable.
-    pub fn downgrade(self) -> DynBuffer<'a> {
-        unsafe { DynBuffer::from_raw(self.as_ptr(), self.count(), self.as_datatype()) }
+unsafe impl<'b, B: ?Sized> PointerMut for TruncatedMut<'b, B>
+where
+    B: 'b + BufferMut,
+{
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.buffer.pointer_mut()
     }
 }
 
-/// A buffer with a user specified count and datatype
+unsafe impl<'b, B: ?Sized> BufferMut for TruncatedMut<'b, B> where B: 'b + BufferMut {}
+
+/// A pair of distinct send and receive buffers sharing a user specified count and datatype
+///
+/// As a `Buffer` it refers to the send buffer and as a `BufferMut` to the receive buffer. Use
+/// `split()` to obtain both at the same time, e.g. for `send_receive_into()`.
+///
+/// The buffers must not overlap, since MPI does not allow send and receive buffers to alias.
+/// Operations that read and write the same buffer are expressed with a single `BufferMut`
+/// instead, e.g. `send_receive_replace_into()`.
 ///
 /// # Safety
 ///
-/// Views can be used to instruct the underlying MPI library to rummage around at arbitrary
-/// locations in memory. This might be controlled later on using datatype bounds an slice lengths
-/// but for now, all View constructors are marked `unsafe`.
-pub struct View<'d, 'b, D, B: ?Sized>
+/// See `View`. Additionally, both buffers must be large enough to hold `count` instances of the
+/// datatype.
+pub struct DualView<'d, 's, 'r, D, S: ?Sized, R: ?Sized>
 where
     D: 'd + Datatype,
-    B: 'b + Pointer,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
 {
     datatype: &'d D,
     count: Count,
-    buffer: &'b B,
+    send_buffer: &'s S,
+    receive_buffer: &'r mut R,
 }
 
-impl<'d, 'b, D, B: ?Sized> View<'d, 'b, D, B>
+impl<'d, 's, 'r, D, S: ?Sized, R: ?Sized> DualView<'d, 's, 'r, D, S, R>
 where
     D: 'd + Datatype,
-    B: 'b + Pointer,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
 {
-    /// Return a view of `buffer` containing `count` instances of MPI datatype `datatype`.
+    /// Return a view of `send_buffer` and `receive_buffer` each containing `count` instances of
+    /// MPI datatype `datatype`.
     ///
     /// # Examples
-    /// See `examples/contiguous.rs`, `examples/vector.rs`
+    /// See `examples/dual_view.rs`
+    ///
+    /// # Safety
+    /// See the documentation of `DualView`.
     pub unsafe fn with_count_and_datatype(
-        buffer: &'b B,
+        send_buffer: &'s S,
+        receive_buffer: &'r mut R,
         count: Count,
         datatype: &'d D,
-    ) -> View<'d, 'b, D, B> {
-        View {
+    ) -> DualView<'d, 's, 'r, D, S, R> {
+        DualView {
             datatype,
             count,
-            buffer,
+            send_buffer,
+            receive_buffer,
         }
     }
+
+    /// Splits into a `View` of the send buffer and a `MutView` of the receive buffer.
+    ///
+    /// # Examples
+    /// See `examples/dual_view.rs`
+    pub fn split(&mut self) -> (View<'d, '_, D, S>, MutView<'d, '_, D, R>) {
+        (
+            View {
+                datatype: self.datatype,
+                count: self.count,
+                buffer: self.send_buffer,
+            },
+            MutView {
+                datatype: self.datatype,
+                count: self.count,
+                buffer: self.receive_buffer,
+            },
+        )
+    }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> AsDatatype for View<'d, 'b, D, B>
+unsafe impl<'d, 's, 'r, D, S: ?Sized, R: ?Sized> AsDatatype for DualView<'d, 's, 'r, D, S, R>
 where
     D: 'd + Datatype,
-    B: 'b + Pointer,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
 {
     type Out = &'d D;
     fn as_datatype(&self) -> Self::Out {
@@ -1192,76 +6691,94 @@ where
     }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> Collection for View<'d, 'b, D, B>
+unsafe impl<'d, 's, 'r, D, S: ?Sized, R: ?Sized> Collection for DualView<'d, 's, 'r, D, S, R>
 where
     D: 'd + Datatype,
-    B: 'b + Pointer,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
 {
     fn count(&self) -> Count {
         self.count
     }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> Pointer for View<'d, 'b, D, B>
+unsafe impl<'d, 's, 'r, D, S: ?Sized, R: ?Sized> Pointer for DualView<'d, 's, 'r, D, S, R>
 where
     D: 'd + Datatype,
-    B: 'b + Pointer,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
 {
     unsafe fn pointer(&self) -> *const c_void {
-        self.buffer.pointer()
+        self.send_buffer.pointer()
     }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> Buffer for View<'d, 'b, D, B>
+unsafe impl<'d, 's, 'r, D, S: ?Sized, R: ?Sized> PointerMut for DualView<'d, 's, 'r, D, S, R>
 where
     D: 'd + Datatype,
-    B: 'b + Pointer,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
 {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.receive_buffer.pointer_mut()
+    }
 }
 
-/// A buffer with a user specified count and datatype
+unsafe impl<'d, 's, 'r, D, S: ?Sized, R: ?Sized> Buffer for DualView<'d, 's, 'r, D, S, R>
+where
+    D: 'd + Datatype,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
+{
+}
+
+unsafe impl<'d, 's, 'r, D, S: ?Sized, R: ?Sized> BufferMut for DualView<'d, 's, 'r, D, S, R>
+where
+    D: 'd + Datatype,
+    S: 's + Pointer,
+    R: 'r + PointerMut,
+{
+}
+
+/// A buffer starting at `MPI_BOTTOM`, i.e. address zero, so that the displacements of its
+/// datatype are absolute addresses, e.g. as returned by `address_of()`.
 ///
-/// # Safety
+/// This can describe objects scattered throughout memory, like several global variables, as a
+/// single message. There is no mutable counterpart, as the absolute addresses only carry shared
+/// access to the objects they were taken from.
 ///
-/// Views can be used to instruct the underlying MPI library to rummage around at arbitrary
-/// locations in memory. This might be controlled later on using datatype bounds an slice lengths
-/// but for now, all View constructors are marked `unsafe`.
-pub struct MutView<'d, 'b, D, B: ?Sized>
+/// # Examples
+/// See `examples/bottom_buffer.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.5, 4.1.12
+pub struct BottomBuffer<'d, D>
 where
     D: 'd + Datatype,
-    B: 'b + PointerMut,
 {
     datatype: &'d D,
     count: Count,
-    buffer: &'b mut B,
 }
 
-impl<'d, 'b, D, B: ?Sized> MutView<'d, 'b, D, B>
+impl<'d, D> BottomBuffer<'d, D>
 where
     D: 'd + Datatype,
-    B: 'b + PointerMut,
 {
-    /// Return a view of `buffer` containing `count` instances of MPI datatype `datatype`.
+    /// Return a buffer of `count` instances of `datatype` starting at `MPI_BOTTOM`.
     ///
-    /// # Examples
-    /// See `examples/contiguous.rs`, `examples/vector.rs`
-    pub unsafe fn with_count_and_datatype(
-        buffer: &'b mut B,
-        count: Count,
-        datatype: &'d D,
-    ) -> MutView<'d, 'b, D, B> {
-        MutView {
-            datatype,
-            count,
-            buffer,
-        }
+    /// # Safety
+    ///
+    /// The displacements of `datatype` must be the absolute addresses of memory that is valid
+    /// for reads for as long as the buffer is in use.
+    pub unsafe fn with_count_and_datatype(count: Count, datatype: &'d D) -> Self {
+        BottomBuffer { datatype, count }
     }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> AsDatatype for MutView<'d, 'b, D, B>
+unsafe impl<'d, D> AsDatatype for BottomBuffer<'d, D>
 where
     D: 'd + Datatype,
-    B: 'b + PointerMut,
 {
     type Out = &'d D;
     fn as_datatype(&self) -> Self::Out {
@@ -1269,31 +6786,401 @@ where
     }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> Collection for MutView<'d, 'b, D, B>
+unsafe impl<'d, D> Collection for BottomBuffer<'d, D>
 where
     D: 'd + Datatype,
-    B: 'b + PointerMut,
 {
     fn count(&self) -> Count {
         self.count
     }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> PointerMut for MutView<'d, 'b, D, B>
+unsafe impl<'d, D> Pointer for BottomBuffer<'d, D>
 where
     D: 'd + Datatype,
-    B: 'b + PointerMut,
 {
-    unsafe fn pointer_mut(&mut self) -> *mut c_void {
-        self.buffer.pointer_mut()
+    unsafe fn pointer(&self) -> *const c_void {
+        unsafe_extern_static!(ffi::RSMPI_BOTTOM)
     }
 }
 
-unsafe impl<'d, 'b, D, B: ?Sized> BufferMut for MutView<'d, 'b, D, B>
+unsafe impl<'d, D> Buffer for BottomBuffer<'d, D> where D: 'd + Datatype {}
+
+/// The elements of a `VecDeque`, in order and without copying them into contiguous memory.
+///
+/// A `VecDeque` stores its elements in up to two slices, see `VecDeque::as_slices()`. The datatype
+/// of this buffer refers to the absolute addresses of both slices and the buffer itself starts at
+/// `MPI_BOTTOM`, like a `BottomBuffer`. The message consists of `len()` elements of `T`, so it can
+/// be received into any buffer of `T`, e.g. a slice or a `Vec`.
+///
+/// If mutable access is available, `VecDeque::make_contiguous()` yields a slice which can be
+/// sent directly instead.
+///
+/// # Examples
+/// See `examples/send_vecdeque.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.2, 4.1.12
+pub struct DequeBuffer<'a> {
+    datatype: UserDatatype,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> DequeBuffer<'a> {
+    /// Describes the elements of `deque`.
+    pub fn new<T>(deque: &'a VecDeque<T>) -> Self
+    where
+        T: Equivalence,
+    {
+        let (front, back) = deque.as_slices();
+        DequeBuffer {
+            datatype: UserDatatype::heterogeneous_indexed(
+                &[front.count(), back.count()],
+                &[address_of(front), address_of(back)],
+                &T::equivalent_datatype(),
+            ),
+            phantom: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a> AsDatatype for DequeBuffer<'a> {
+    type Out = DatatypeRef<'a>;
+    fn as_datatype(&self) -> Self::Out {
+        unsafe { DatatypeRef::from_raw_unchecked(self.datatype.as_raw()) }
+    }
+}
+
+unsafe impl<'a> Collection for DequeBuffer<'a> {
+    fn count(&self) -> Count {
+        1
+    }
+}
+
+unsafe impl<'a> Pointer for DequeBuffer<'a> {
+    unsafe fn pointer(&self) -> *const c_void {
+        unsafe_extern_static!(ffi::RSMPI_BOTTOM)
+    }
+}
+
+unsafe impl<'a> Buffer for DequeBuffer<'a> {}
+
+/// Reinterprets the memory of a value or slice as raw bytes.
+///
+/// Bytes are sent as `MPI_BYTE`, so MPI transfers them without any conversion, even between
+/// processes with different data representations.
+///
+/// This is only implemented for types without padding, see `NoPadding`, since the padding of any
+/// other type would be sent as well, which reads uninitialized memory:
+///
+/// ```compile_fail
+/// # use mpi::datatype::UserDatatype;
+/// # use mpi::traits::*;
+/// #[repr(C)]
+/// struct Padded {
+///     tag: u8,
+///     value: u32,
+/// }
+///
+/// unsafe impl Equivalence for Padded {
+///     type Out = UserDatatype;
+///     fn equivalent_datatype() -> Self::Out {
+///         let types = [u8::equivalent_datatype(), u32::equivalent_datatype()];
+///         UserDatatype::structured(&[1, 1], &[0, 4], &types)
+///     }
+/// }
+///
+/// let padded = Padded { tag: 1, value: 2 };
+/// let bytes = padded.as_byte_buffer();
+/// ```
+///
+/// # Examples
+/// See `examples/byte_buffer.rs`
+///
+/// # Standard section(s)
+///
+/// 3.3.1
+pub trait AsByteBuffer {
+    /// A buffer of `size_of_val(self)` instances of `MPI_BYTE` starting at the address of `self`
+    fn as_byte_buffer(&self) -> ByteBuffer<'_>;
+
+    /// A mutable buffer of `size_of_val(self)` instances of `MPI_BYTE` starting at the address of
+    /// `self`
+    ///
+    /// # Safety
+    ///
+    /// The bytes received into the buffer must form valid values of the type of `self`, e.g.
+    /// because they were sent from a value of the same type by a process with the same data
+    /// representation.
+    unsafe fn as_byte_buffer_mut(&mut self) -> ByteBufferMut<'_>;
+}
+
+impl<T> AsByteBuffer for T
 where
-    D: 'd + Datatype,
-    B: 'b + PointerMut,
+    T: NoPadding,
+{
+    fn as_byte_buffer(&self) -> ByteBuffer<'_> {
+        ByteBuffer::new(self)
+    }
+
+    unsafe fn as_byte_buffer_mut(&mut self) -> ByteBufferMut<'_> {
+        ByteBufferMut::new(self)
+    }
+}
+
+impl<T> AsByteBuffer for [T]
+where
+    T: NoPadding,
+{
+    fn as_byte_buffer(&self) -> ByteBuffer<'_> {
+        ByteBuffer::new(self)
+    }
+
+    unsafe fn as_byte_buffer_mut(&mut self) -> ByteBufferMut<'_> {
+        ByteBufferMut::new(self)
+    }
+}
+
+impl<T, const N: usize> AsByteBuffer for [T; N]
+where
+    T: NoPadding,
 {
+    fn as_byte_buffer(&self) -> ByteBuffer<'_> {
+        ByteBuffer::new(self)
+    }
+
+    unsafe fn as_byte_buffer_mut(&mut self) -> ByteBufferMut<'_> {
+        ByteBufferMut::new(self)
+    }
+}
+
+fn byte_count<T: ?Sized>(value: &T) -> Count {
+    size_of_val(value)
+        .value_as()
+        .expect("Size of buffer cannot be expressed as an MPI Count.")
+}
+
+/// The memory of a value or slice as a buffer of `MPI_BYTE`, see `AsByteBuffer`
+#[derive(Copy, Clone, Debug)]
+pub struct ByteBuffer<'a> {
+    pointer: *const c_void,
+    count: Count,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> ByteBuffer<'a> {
+    fn new<T: ?Sized>(value: &'a T) -> Self {
+        let pointer: *const T = value;
+        ByteBuffer {
+            pointer: pointer as *const c_void,
+            count: byte_count(value),
+            phantom: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a> AsDatatype for ByteBuffer<'a> {
+    type Out = SystemDatatype;
+    fn as_datatype(&self) -> Self::Out {
+        byte_datatype()
+    }
+}
+
+unsafe impl<'a> Collection for ByteBuffer<'a> {
+    fn count(&self) -> Count {
+        self.count
+    }
+}
+
+unsafe impl<'a> Pointer for ByteBuffer<'a> {
+    unsafe fn pointer(&self) -> *const c_void {
+        self.pointer
+    }
+}
+
+unsafe impl<'a> Buffer for ByteBuffer<'a> {}
+
+/// The memory of a value or slice as a mutable buffer of `MPI_BYTE`, see `AsByteBuffer`
+#[derive(Debug)]
+pub struct ByteBufferMut<'a> {
+    pointer: *mut c_void,
+    count: Count,
+    phantom: PhantomData<&'a mut ()>,
+}
+
+impl<'a> ByteBufferMut<'a> {
+    fn new<T: ?Sized>(value: &'a mut T) -> Self {
+        let count = byte_count(value);
+        let pointer: *mut T = value;
+        ByteBufferMut {
+            pointer: pointer as *mut c_void,
+            count,
+            phantom: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a> AsDatatype for ByteBufferMut<'a> {
+    type Out = SystemDatatype;
+    fn as_datatype(&self) -> Self::Out {
+        byte_datatype()
+    }
+}
+
+unsafe impl<'a> Collection for ByteBufferMut<'a> {
+    fn count(&self) -> Count {
+        self.count
+    }
+}
+
+unsafe impl<'a> PointerMut for ByteBufferMut<'a> {
+    unsafe fn pointer_mut(&mut self) -> *mut c_void {
+        self.pointer
+    }
+}
+
+unsafe impl<'a> BufferMut for ByteBufferMut<'a> {}
+
+/// Several arrays of equal length, sent as if they were interleaved into a single array of
+/// records.
+///
+/// Record `i` consists of element `i` of every array, in the order the arrays were added. The
+/// arrays are not copied; instead the datatype of this buffer refers to the absolute addresses of
+/// their elements and the buffer itself starts at `MPI_BOTTOM`, like a `BottomBuffer`. A matching
+/// receive of the records into an array of structs therefore interleaves the arrays in one call.
+///
+/// If the elements of all arrays have the same size, the buffer consists of as many instances of
+/// a single record datatype as there are records. Otherwise, the datatype lists every element of
+/// every array, so constructing it takes time and memory proportional to the number of records.
+///
+/// # Examples
+/// See `examples/interleaved.rs`
+///
+/// # Standard section(s)
+///
+/// 4.1.5, 4.1.12
+pub struct Interleaved<'a> {
+    datatype: UserDatatype,
+    count: Count,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Interleaved<'a> {
+    /// Starts describing the arrays to interleave
+    pub fn builder() -> InterleavedBuilder<'a> {
+        InterleavedBuilder {
+            len: None,
+            addresses: Vec::new(),
+            strides: Vec::new(),
+            types: Vec::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a> AsDatatype for Interleaved<'a> {
+    type Out = DatatypeRef<'a>;
+    fn as_datatype(&self) -> Self::Out {
+        unsafe { DatatypeRef::from_raw_unchecked(self.datatype.as_raw()) }
+    }
+}
+
+unsafe impl<'a> Collection for Interleaved<'a> {
+    fn count(&self) -> Count {
+        self.count
+    }
+}
+
+unsafe impl<'a> Pointer for Interleaved<'a> {
+    unsafe fn pointer(&self) -> *const c_void {
+        unsafe_extern_static!(ffi::RSMPI_BOTTOM)
+    }
+}
+
+unsafe impl<'a> Buffer for Interleaved<'a> {}
+
+/// Collects the arrays of an `Interleaved` buffer one at a time.
+pub struct InterleavedBuilder<'a> {
+    len: Option<usize>,
+    addresses: Vec<Address>,
+    strides: Vec<Address>,
+    types: Vec<Box<dyn AsRaw<Raw = MPI_Datatype>>>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> InterleavedBuilder<'a> {
+    /// Adds `values` as the next field of the records.
+    ///
+    /// # Panics
+    ///
+    /// If `values` does not have the same length as the arrays added before.
+    pub fn field<T>(mut self, values: &'a [T]) -> Self
+    where
+        T: Equivalence,
+        T::Out: 'static,
+    {
+        let len = *self.len.get_or_insert(values.len());
+        assert_eq!(len, values.len(), "all fields must have the same length");
+
+        self.addresses.push(address_of(values));
+        self.strides.push(
+            Address::try_from(size_of::<T>())
+                .expect("Element size cannot be expressed as an MPI Address."),
+        );
+        self.types.push(Box::new(T::equivalent_datatype()));
+        self
+    }
+
+    /// Builds the buffer of all records
+    pub fn build(self) -> Interleaved<'a> {
+        let len = self.len.unwrap_or(0);
+        let types: SmallVec<[UncommittedDatatypeRef<'_>; 8]> = self
+            .types
+            .iter()
+            .map(|t| unsafe { UncommittedDatatypeRef::from_raw(t.as_raw()) })
+            .collect();
+
+        // With a common stride, each record is the first one moved by that stride.
+        if let Some(&stride) = self.strides.first() {
+            if self.strides.iter().all(|&s| s == stride) {
+                let record = UncommittedUserDatatype::structured(
+                    &vec![1; types.len()],
+                    &self.addresses,
+                    &types,
+                );
+                return Interleaved {
+                    datatype: UserDatatype::resized(&record, record.lower_bound(), stride),
+                    count: len
+                        .value_as()
+                        .expect("Number of records cannot be expressed as an MPI Count."),
+                    phantom: PhantomData,
+                };
+            }
+        }
+
+        let mut displacements = Vec::with_capacity(len * types.len());
+        let mut all_types = Vec::with_capacity(len * types.len());
+        for i in 0..len {
+            for (j, datatype) in types.iter().enumerate() {
+                let offset: MPI_Aint = i
+                    .value_as()
+                    .expect("Record index cannot be expressed as an MPI Address.");
+                displacements.push(self.addresses[j] + self.strides[j] * offset);
+                all_types.push(*datatype);
+            }
+        }
+
+        Interleaved {
+            datatype: UserDatatype::structured(
+                &vec![1; displacements.len()],
+                &displacements,
+                &all_types,
+            ),
+            count: 1,
+            phantom: PhantomData,
+        }
+    }
 }
 
 /// Describes how a `Buffer` is partitioned by specifying the count of elements and displacement
@@ -1320,6 +7207,38 @@ pub trait PartitionedBuffer: Partitioned + Pointer + AsDatatype {}
 /// A mutable buffer that is `Partitioned`
 pub trait PartitionedBufferMut: Partitioned + PointerMut + AsDatatype {}
 
+/// Computes the displacements of consecutive partitions from their `counts`.
+///
+/// The displacements are the exclusive prefix sum of `counts`, i.e. the partitions are laid out
+/// back to back in the order given and the first one starts at displacement `0`.
+///
+/// # Examples
+///
+/// ```
+/// use mpi::datatype::displacements_from_counts;
+///
+/// assert_eq!(vec![0, 2, 2, 5], displacements_from_counts(&[2, 0, 3, 1]));
+/// assert!(displacements_from_counts(&[]).is_empty());
+/// ```
+///
+/// See also `examples/gather_varcount.rs`
+///
+/// # Panics
+///
+/// If the sum of `counts` overflows a `Count`.
+pub fn displacements_from_counts(counts: &[Count]) -> Vec<Count> {
+    counts
+        .iter()
+        .scan(0, |acc: &mut Count, &count| {
+            let displ = *acc;
+            *acc = acc
+                .checked_add(count)
+                .expect("partition displacements overflow `Count`");
+            Some(displ)
+        })
+        .collect()
+}
+
 /// Adds a partitioning to an existing `Buffer` so that it becomes `Partitioned`
 pub struct Partition<'b, B: 'b + ?Sized, C, D> {
     buf: &'b B,
@@ -1464,13 +7383,21 @@ where
 
 /// Returns the address of the argument in a format suitable for use with datatype constructors
 ///
+/// This stays infallible: `MPI_Get_address()` only converts the pointer behind a valid reference
+/// into an `MPI_Aint`, without any communication or allocation that could fail. Should an MPI
+/// library still report an error, it is handled by the MPI error handler, which aborts the
+/// program by default, like for the other MPI calls made by this crate that do not return a
+/// `Result`.
+///
 /// # Examples
-/// See `examples/structured.rs`
+/// See `examples/address_of.rs`
 ///
 /// # Standard section(s)
 ///
 /// 4.1.5
-pub fn address_of<T>(x: &T) -> Address {
+pub fn address_of<T: ?Sized>(x: &T) -> Address {
     let x: *const T = x;
-    unsafe { with_uninitialized(|address| ffi::MPI_Get_address(x as *const c_void, address)).1 }
+    Address(unsafe {
+        with_uninitialized(|address| ffi::MPI_Get_address(x as *const c_void, address)).1
+    })
 }
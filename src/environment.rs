@@ -20,6 +20,7 @@ use std::{
 use conv::ConvUtil;
 use once_cell::sync::Lazy;
 
+use crate::datatype::{free_cached_datatypes, free_uncommitted_keyval};
 use crate::ffi;
 use crate::topology::SystemCommunicator;
 use crate::{with_uninitialized, with_uninitialized2};
@@ -104,6 +105,8 @@ impl Drop for Universe {
         let mut _universe_state = UNIVERSE_STATE.write().unwrap();
 
         self.detach_buffer();
+        free_cached_datatypes();
+        free_uncommitted_keyval();
         unsafe {
             ffi::MPI_Finalize();
         }
@@ -118,9 +118,16 @@
 //!
 //! [MPIspec]: http://www.mpi-forum.org/docs/docs.html
 
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::Sum;
 use std::mem::MaybeUninit;
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 use std::os::raw::c_int;
 
+use conv::ConvUtil;
+
 /// The raw C language MPI API
 ///
 /// Documented in the [Message Passing Interface specification][spec]
@@ -180,16 +187,178 @@ pub use crate::environment::{
     initialize, initialize_with_threading, time, time_resolution, Threading,
 };
 
-use crate::ffi::MPI_Aint;
+use crate::ffi::{MPI_Aint, MPI_Count};
 
 /// Encodes error values returned by MPI functions.
 pub type Error = c_int;
 /// Encodes number of values in multi-value messages.
+///
+/// A `Count` is always measured in elements of some datatype, never in bytes. Element counts,
+/// strides and displacements (e.g. in `UserDatatype::vector()`) are `Count`s while their byte
+/// based counterparts (e.g. in `UserDatatype::heterogeneous_vector()`) are `Address`es. As
+/// `Address` is a distinct type, passing one where the other is expected is rejected by the
+/// compiler instead of being silently reinterpreted:
+///
+/// ```compile_fail
+/// use mpi::datatype::UserDatatype;
+/// use mpi::traits::*;
+/// use mpi::Address;
+///
+/// let stride = Address(16);
+/// // A vector stride is counted in elements of `f64`, not in bytes.
+/// let t = UserDatatype::vector(2, 1, stride, &f64::equivalent_datatype());
+/// ```
+///
+/// Converting between the two is explicit, e.g. `Address::from(count)` or
+/// `Count::try_from(address)`.
 pub type Count = c_int;
 /// Can be used to tag messages on the sender side and match on the receiver side.
 pub type Tag = c_int;
+
 /// An address in memory
-pub type Address = MPI_Aint;
+///
+/// Addresses, byte displacements, byte strides and extents are measured in bytes. See `Count` for
+/// the element based counterpart, which cannot be passed as an `Address`:
+///
+/// ```compile_fail
+/// use mpi::datatype::UserDatatype;
+/// use mpi::traits::*;
+///
+/// let displacements: [mpi::Count; 2] = [0, 2];
+/// // Heterogeneous displacements are given in bytes.
+/// let t = UserDatatype::heterogeneous_indexed(&[1, 1], &displacements, &f64::equivalent_datatype());
+/// ```
+///
+/// The wrapped `MPI_Aint` is public, e.g. to write literals as `Address(16)`. An `Address` can be
+/// moved by another one, scaled by a number of elements and converted from a `Count` or to and
+/// from a `usize`, e.g. an offset returned by `memoffset::offset_of!`.
+///
+/// # Standard section(s)
+///
+/// 2.5.6, 4.1.5
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Address(pub MPI_Aint);
+
+impl Address {
+    /// The absolute value of this address, e.g. the length of a byte stride in either direction
+    pub fn abs(self) -> Address {
+        Address(self.0.abs())
+    }
+
+    /// Adds `rhs`, returning `None` on overflow
+    pub fn checked_add(self, rhs: Address) -> Option<Address> {
+        self.0.checked_add(rhs.0).map(Address)
+    }
+
+    /// Scales this address by `factor`, returning `None` on overflow
+    pub fn checked_mul(self, factor: MPI_Aint) -> Option<Address> {
+        self.0.checked_mul(factor).map(Address)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// A number of bytes given as a `Count`, i.e. as an `int` in the C API
+impl From<Count> for Address {
+    fn from(count: Count) -> Address {
+        Address(
+            count
+                .value_as()
+                .expect("Count cannot be expressed as an MPI Address."),
+        )
+    }
+}
+
+impl TryFrom<Address> for Count {
+    type Error = TryFromIntError;
+    // `MPI_Aint` is `i64` with some implementations and `isize` or even `i32` with others.
+    #[allow(trivial_numeric_casts)]
+    fn try_from(address: Address) -> Result<Count, TryFromIntError> {
+        Count::try_from(address.0 as i64)
+    }
+}
+
+impl TryFrom<usize> for Address {
+    type Error = TryFromIntError;
+    fn try_from(offset: usize) -> Result<Address, TryFromIntError> {
+        MPI_Aint::try_from(offset).map(Address)
+    }
+}
+
+impl TryFrom<Address> for usize {
+    type Error = TryFromIntError;
+    fn try_from(address: Address) -> Result<usize, TryFromIntError> {
+        usize::try_from(address.0)
+    }
+}
+
+impl Add for Address {
+    type Output = Address;
+    fn add(self, rhs: Address) -> Address {
+        Address(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Address {
+    fn add_assign(&mut self, rhs: Address) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Address {
+    type Output = Address;
+    fn sub(self, rhs: Address) -> Address {
+        Address(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Address {
+    fn sub_assign(&mut self, rhs: Address) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Address {
+    type Output = Address;
+    fn neg(self) -> Address {
+        Address(-self.0)
+    }
+}
+
+impl Mul<MPI_Aint> for Address {
+    type Output = Address;
+    fn mul(self, factor: MPI_Aint) -> Address {
+        Address(self.0 * factor)
+    }
+}
+
+/// `count` elements of an extent
+impl Mul<Address> for Count {
+    type Output = Address;
+    fn mul(self, extent: Address) -> Address {
+        Address::from(self) * extent.0
+    }
+}
+
+impl Sum for Address {
+    fn sum<I: Iterator<Item = Address>>(iter: I) -> Address {
+        iter.fold(Address(0), Add::add)
+    }
+}
+
+/// A count or size that may exceed the range of `Count`, as used by the large count (`_x`)
+/// functions introduced with MPI 3.0.
+pub type LargeCount = MPI_Count;
 
 /// IntArray is used to translate Rust bool values to and from the int-bool types preferred by MPI
 /// without incurring allocation in the common case.
@@ -12,9 +12,13 @@
 //! `MPI_Rsend_init()`, `MPI_Recv_init()`, `MPI_Start()`, `MPI_Startall()`
 
 use std::alloc::{self, Layout};
+use std::collections::VecDeque;
 use std::mem::{transmute, MaybeUninit};
-use std::{fmt, ptr};
+use std::string::FromUtf8Error;
+use std::{fmt, ptr, slice};
 
+#[cfg(feature = "bitvec")]
+use bitvec::{order::BitOrder, vec::BitVec};
 use conv::ConvUtil;
 
 use super::{Count, Tag};
@@ -23,6 +27,7 @@ use crate::ffi;
 use crate::ffi::{MPI_Message, MPI_Status};
 
 use crate::datatype::traits::*;
+use crate::datatype::{DequeBuffer, Halo2D, Side};
 use crate::raw::traits::*;
 use crate::request::{Request, Scope, StaticScope};
 use crate::topology::traits::*;
@@ -31,6 +36,16 @@ use crate::{with_uninitialized, with_uninitialized2};
 
 // TODO: rein in _with_tag ugliness, use optional tags or make tag part of Source and Destination
 
+/// The largest number of elements the chunked send and receive operations put into one message
+const MAX_CHUNK_LEN: usize = Count::MAX as usize;
+
+fn check_chunk_len(chunk_len: usize) {
+    assert!(
+        chunk_len > 0 && chunk_len <= MAX_CHUNK_LEN,
+        "'chunk_len' must be positive and fit into a Count"
+    );
+}
+
 /// Point to point communication traits
 pub mod traits {
     pub use super::{Destination, MatchedReceiveVec, Source};
@@ -236,6 +251,121 @@ pub unsafe trait Source: AsCommunicator {
         self.receive_into_with_tag(buf, unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
     }
 
+    /// Receive a message into a `Buffer` and count the predefined elements received.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` into `Buffer` `buf` and return the
+    /// number of predefined elements of its datatype that arrived, see `Status::elements()`, such
+    /// as when the message is shorter than the buffer or ends within an instance of a derived
+    /// datatype.
+    ///
+    /// # Examples
+    /// See `examples/receive_elements.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 4.1.11
+    fn receive_elements_into_with_tag<Buf: ?Sized>(
+        &self,
+        buf: &mut Buf,
+        tag: Tag,
+    ) -> (Count, Status)
+    where
+        Buf: BufferMut,
+    {
+        let status = self.receive_into_with_tag(buf, tag);
+        (status.elements(buf.as_datatype()), status)
+    }
+
+    /// Receive a message into a `Buffer` and count the predefined elements received.
+    ///
+    /// Receive a message from `Source` `&self` into `Buffer` `buf` and return the number of
+    /// predefined elements of its datatype that arrived, see `Status::elements()`.
+    ///
+    /// # Examples
+    /// See `examples/receive_elements.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 4.1.11
+    fn receive_elements_into<Buf: ?Sized>(&self, buf: &mut Buf) -> (Count, Status)
+    where
+        Buf: BufferMut,
+    {
+        self.receive_elements_into_with_tag(buf, unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
+    }
+
+    /// Receive a message sent by `Destination::send_chunked_with_tag()` into a slice.
+    ///
+    /// Receive `buf.len()` elements from `Source` `&self` tagged `tag` as consecutive messages of
+    /// at most `chunk_len` elements each. Once the first chunk has been received, the remaining
+    /// chunks are received from the same process with the same tag, so `AnyProcess` and
+    /// `RSMPI_ANY_TAG` can be used. The slice must be exactly as long as the sent slice and
+    /// `chunk_len` must match the one used for sending. Returns the status of the last chunk.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_len` is zero or exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/send_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_chunked_with_tag<T>(&self, buf: &mut [T], chunk_len: usize, tag: Tag) -> Status
+    where
+        T: Equivalence,
+    {
+        check_chunk_len(chunk_len);
+
+        let mut chunks = buf.chunks_mut(chunk_len);
+        let mut status = match chunks.next() {
+            Some(chunk) => self.receive_into_with_tag(chunk, tag),
+            None => return self.receive_into_with_tag(buf, tag),
+        };
+        for chunk in chunks {
+            let source = self.as_communicator().process_at_rank(status.source_rank());
+            status = source.receive_into_with_tag(chunk, status.tag());
+        }
+        status
+    }
+
+    /// Receive a message sent by `Destination::send_large_with_tag()` into a slice.
+    ///
+    /// Like `receive_into_chunked_with_tag()` with chunks of `Count::MAX` elements, so that slices
+    /// of any length can be received. Slices that fit into a single message are received by a
+    /// single operation.
+    ///
+    /// # Examples
+    /// See `examples/send_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_large_with_tag<T>(&self, buf: &mut [T], tag: Tag) -> Status
+    where
+        T: Equivalence,
+    {
+        self.receive_into_chunked_with_tag(buf, MAX_CHUNK_LEN, tag)
+    }
+
+    /// Receive a message sent by `Destination::send_large()` into a slice.
+    ///
+    /// Like `receive_into_large_with_tag()` for messages with any tag.
+    ///
+    /// # Examples
+    /// See `examples/send_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_large<T>(&self, buf: &mut [T]) -> Status
+    where
+        T: Equivalence,
+    {
+        self.receive_into_large_with_tag(buf, unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
+    }
+
     /// Receive a message containing multiple instances of type `Msg` into a `Vec`.
     ///
     /// Receive a message from `Source` `&self` tagged `tag` containing multiple instances of type
@@ -269,6 +399,234 @@ pub unsafe trait Source: AsCommunicator {
         self.receive_vec_with_tag(unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
     }
 
+    /// Receive a message containing multiple instances of type `Msg` into a boxed slice.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` into a `Box<[Msg]>` of exactly the
+    /// length of the message, which is probed first. Empty messages yield an empty slice.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 3.8.2
+    fn receive_boxed_with_tag<Msg>(&self, tag: Tag) -> (Box<[Msg]>, Status)
+    where
+        Msg: Equivalence,
+    {
+        // The `Vec` is allocated with the exact length of the message, so this does not
+        // reallocate.
+        let (msg, status) = self.receive_vec_with_tag(tag);
+        (msg.into_boxed_slice(), status)
+    }
+
+    /// Receive a message containing multiple instances of type `Msg` into a boxed slice.
+    ///
+    /// Receive a message from `Source` `&self` into a `Box<[Msg]>` of exactly the length of the
+    /// message.
+    ///
+    /// # Examples
+    /// See `examples/receive_boxed.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 3.8.2
+    fn receive_boxed<Msg>(&self) -> (Box<[Msg]>, Status)
+    where
+        Msg: Equivalence,
+    {
+        self.receive_boxed_with_tag(unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
+    }
+
+    /// Receive a `BitVec` sent by `Destination::send_bitvec_with_tag()`.
+    ///
+    /// Receives the number of bits from `Source` `&self` in a message tagged `tag`, then the words
+    /// storing them from the same process with the same tag. The returned `Status` is that of the
+    /// second message. The bits have to be received with the same `BitOrder` they were sent with.
+    ///
+    /// # Panics
+    ///
+    /// If the number of bits cannot be expressed as a `usize` or exceeds the number of bits in
+    /// the words received, i.e. if the messages were not sent by `send_bitvec_with_tag()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    #[cfg(feature = "bitvec")]
+    fn receive_bitvec_with_tag<O>(&self, tag: Tag) -> (BitVec<usize, O>, Status)
+    where
+        O: BitOrder,
+    {
+        let (len, status) = self.receive_with_tag::<u64>(tag);
+        let len: usize = len
+            .value_as()
+            .expect("Number of bits cannot be expressed as a usize.");
+
+        let source = self.as_communicator().process_at_rank(status.source_rank());
+        let (words, status) = source.receive_vec_with_tag::<usize>(status.tag());
+        let mut bits = BitVec::from_vec(words);
+        assert!(
+            len <= bits.len(),
+            "Received a length of {} bits, but only {} bits of words to hold them.",
+            len,
+            bits.len()
+        );
+        bits.truncate(len);
+        (bits, status)
+    }
+
+    /// Receive a `BitVec` sent by `Destination::send_bitvec()`.
+    ///
+    /// Like `receive_bitvec_with_tag()` for messages with any tag.
+    ///
+    /// # Examples
+    /// See `examples/bitvec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    #[cfg(feature = "bitvec")]
+    fn receive_bitvec<O>(&self) -> (BitVec<usize, O>, Status)
+    where
+        O: BitOrder,
+    {
+        self.receive_bitvec_with_tag(unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
+    }
+
+    /// Receive a message containing multiple instances of type `Msg` and convert them to `T`.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` containing multiple instances of type
+    /// `Msg`, e.g. `f32`, and convert them into a `Vec` of a wider type `T`, e.g. `f64`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_vec_as_with_tag<Msg, T>(&self, tag: Tag) -> (Vec<T>, Status)
+    where
+        Msg: Equivalence,
+        T: From<Msg>,
+    {
+        let (msg, status) = self.receive_vec_with_tag::<Msg>(tag);
+        (msg.into_iter().map(T::from).collect(), status)
+    }
+
+    /// Receive a message containing multiple instances of type `Msg` and convert them to `T`.
+    ///
+    /// Receive a message from `Source` `&self` containing multiple instances of type `Msg`, e.g.
+    /// `f32`, and convert them into a `Vec` of a wider type `T`, e.g. `f64`.
+    ///
+    /// The message is always received using the datatype of `Msg`, and only lossless conversions
+    /// via `From` are accepted, so narrowing the received values does not compile:
+    ///
+    /// ```compile_fail
+    /// # use mpi::traits::*;
+    /// # let universe = mpi::initialize().unwrap();
+    /// # let world = universe.world();
+    /// let (values, _) = world.process_at_rank(0).receive_vec_as::<f64, f32>();
+    /// ```
+    ///
+    /// # Examples
+    /// See `examples/receive_vec_as.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_vec_as<Msg, T>(&self) -> (Vec<T>, Status)
+    where
+        Msg: Equivalence,
+        T: From<Msg>,
+    {
+        self.receive_vec_as_with_tag::<Msg, T>(unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
+    }
+
+    /// Receive a message containing multiple instances of type `Msg` into an existing `Vec`.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` containing multiple instances of type
+    /// `Msg`. The previous contents of `buf` are replaced by the message, reusing its allocation
+    /// if it is large enough.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 3.8.2
+    fn receive_into_vec_with_tag<Msg>(&self, buf: &mut Vec<Msg>, tag: Tag) -> Status
+    where
+        Msg: Equivalence,
+    {
+        let (message, status) = self.matched_probe_with_tag(tag);
+        let count = status
+            .count(Msg::equivalent_datatype())
+            .value_as()
+            .expect("Message element count cannot be expressed as a usize.");
+
+        buf.clear();
+        buf.reserve(count);
+
+        let status = {
+            let uninit = unsafe {
+                slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut MaybeUninit<Msg>, count)
+            };
+            message.matched_receive_into(uninit)
+        };
+
+        let received: usize = status
+            .count(Msg::equivalent_datatype())
+            .value_as()
+            .expect("Message element count cannot be expressed as a usize.");
+        assert_eq!(
+            count, received,
+            "Received a different number of elements than were probed."
+        );
+        unsafe { buf.set_len(received) };
+
+        status
+    }
+
+    /// Receive a message containing multiple instances of type `Msg` into an existing `Vec`.
+    ///
+    /// Receive a message from `Source` `&self` containing multiple instances of type `Msg`. The
+    /// previous contents of `buf` are replaced by the message, reusing its allocation if it is
+    /// large enough.
+    ///
+    /// # Examples
+    /// See `examples/receive_into_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 3.8.2
+    fn receive_into_vec<Msg>(&self, buf: &mut Vec<Msg>) -> Status
+    where
+        Msg: Equivalence,
+    {
+        self.receive_into_vec_with_tag(buf, unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
+    }
+
+    /// Receive a UTF-8 encoded string.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` containing the bytes of a string sent
+    /// via `Destination::send_str_with_tag()`. The received bytes are returned as an `Err` if they
+    /// are not valid UTF-8.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_string_with_tag(&self, tag: Tag) -> (Result<String, FromUtf8Error>, Status) {
+        let (bytes, status) = self.receive_vec_with_tag::<u8>(tag);
+        (String::from_utf8(bytes), status)
+    }
+
+    /// Receive a UTF-8 encoded string.
+    ///
+    /// Receive a message from `Source` `&self` containing the bytes of a string sent via
+    /// `Destination::send_str()`. The received bytes are returned as an `Err` if they are not
+    /// valid UTF-8.
+    ///
+    /// # Examples
+    /// See `examples/send_receive_str.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_string(&self) -> (Result<String, FromUtf8Error>, Status) {
+        self.receive_string_with_tag(unsafe_extern_static!(ffi::RSMPI_ANY_TAG))
+    }
+
     /// Initiate an immediate (non-blocking) receive operation.
     ///
     /// Initiate receiving a message matching `tag` into `buf`.
@@ -309,6 +667,21 @@ pub unsafe trait Source: AsCommunicator {
     ///
     /// Initiate receiving a message into `buf`.
     ///
+    /// `buf` stays mutably borrowed for as long as `scope`, which the returned `Request` cannot
+    /// outlive, so it can neither be read nor modified before the request has completed:
+    ///
+    /// ```compile_fail
+    /// # use mpi::traits::*;
+    /// # let universe = mpi::initialize().unwrap();
+    /// # let world = universe.world();
+    /// let mut x = 0.0f64;
+    /// mpi::request::scope(|scope| {
+    ///     let request = world.this_process().immediate_receive_into(scope, &mut x);
+    ///     let y = x;
+    ///     request.wait();
+    /// });
+    /// ```
+    ///
     /// # Examples
     /// See `examples/immediate.rs`
     ///
@@ -545,6 +918,185 @@ pub trait Destination: AsCommunicator {
         self.send_with_tag(buf, Tag::default())
     }
 
+    /// Blocking standard mode send operation for `VecDeque`s
+    ///
+    /// Send the elements of `deque` in order to the `Destination` `&self` and tag it, without
+    /// copying them into contiguous memory first, see `DequeBuffer`. The message can be received
+    /// like a slice of `deque.len()` elements.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_vecdeque_with_tag<T>(&self, deque: &VecDeque<T>, tag: Tag)
+    where
+        T: Equivalence,
+    {
+        self.send_with_tag(&DequeBuffer::new(deque), tag)
+    }
+
+    /// Blocking standard mode send operation for `VecDeque`s
+    ///
+    /// Like `send_vecdeque_with_tag()` with the default tag.
+    ///
+    /// # Examples
+    /// See `examples/send_vecdeque.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_vecdeque<T>(&self, deque: &VecDeque<T>)
+    where
+        T: Equivalence,
+    {
+        self.send_vecdeque_with_tag(deque, Tag::default())
+    }
+
+    /// Blocking standard mode send operation for `BitVec`s
+    ///
+    /// Send the bits of `bits` to the `Destination` `&self` as two messages tagged `tag`: the
+    /// number of bits as a `u64`, followed by the `usize` words storing them, i.e.
+    /// `bits.as_raw_slice()`. The bits are not copied or repacked, so they are transferred as
+    /// compactly as they are stored. Receive them via `Source::receive_bitvec_with_tag()`.
+    ///
+    /// How the bits are arranged within a word is determined by the `BitOrder` `O`, so the
+    /// receiver has to use the same one. The words are communicated as `usize`, so MPI converts
+    /// their byte order between processes, but `usize` has to have the same size on both sides.
+    ///
+    /// The receiver assumes that the bits start at the first bit of the first word, so `bits` is
+    /// moved there in place via `BitVec::force_align()` first. This only copies anything if `bits`
+    /// does not start there already, e.g. because it was built from a bit slice starting in the
+    /// middle of a word.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "bitvec")]
+    fn send_bitvec_with_tag<O>(&self, bits: &mut BitVec<usize, O>, tag: Tag)
+    where
+        O: BitOrder,
+    {
+        bits.force_align();
+        let len: u64 = bits
+            .len()
+            .value_as()
+            .expect("Number of bits cannot be expressed as a u64.");
+        self.send_with_tag(&len, tag);
+        self.send_with_tag(bits.as_raw_slice(), tag);
+    }
+
+    /// Blocking standard mode send operation for `BitVec`s
+    ///
+    /// Like `send_bitvec_with_tag()` with the default tag.
+    ///
+    /// # Examples
+    /// See `examples/bitvec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "bitvec")]
+    fn send_bitvec<O>(&self, bits: &mut BitVec<usize, O>)
+    where
+        O: BitOrder,
+    {
+        self.send_bitvec_with_tag(bits, Tag::default())
+    }
+
+    /// Blocking standard mode send operation for strings
+    ///
+    /// Send the UTF-8 encoded bytes of `msg` to the `Destination` `&self` and tag it. No separate
+    /// length needs to be sent as the receiving side obtains it from the message envelope, see
+    /// `Source::receive_string_with_tag()`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_str_with_tag(&self, msg: &str, tag: Tag) {
+        self.send_with_tag(msg.as_bytes(), tag)
+    }
+
+    /// Blocking standard mode send operation for strings
+    ///
+    /// Send the UTF-8 encoded bytes of `msg` to the `Destination` `&self`.
+    ///
+    /// # Examples
+    /// See `examples/send_receive_str.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_str(&self, msg: &str) {
+        self.send_str_with_tag(msg, Tag::default())
+    }
+
+    /// Blocking standard mode send operation in chunks
+    ///
+    /// Send the elements of `buf` to the `Destination` `&self` as consecutive messages of at most
+    /// `chunk_len` elements each, all tagged `tag`. An empty slice is sent as a single empty
+    /// message. The chunks have to be received by `Source::receive_into_chunked_with_tag()` with
+    /// the same `chunk_len`.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_len` is zero or exceeds the range of `Count`.
+    ///
+    /// # Examples
+    /// See `examples/send_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_chunked_with_tag<T>(&self, buf: &[T], chunk_len: usize, tag: Tag)
+    where
+        T: Equivalence,
+    {
+        check_chunk_len(chunk_len);
+
+        if buf.is_empty() {
+            return self.send_with_tag(buf, tag);
+        }
+        for chunk in buf.chunks(chunk_len) {
+            self.send_with_tag(chunk, tag);
+        }
+    }
+
+    /// Blocking standard mode send operation for slices of any length
+    ///
+    /// The number of elements of a message is a `Count`, so this splits slices with more than
+    /// `Count::MAX` elements into several messages, like `send_chunked_with_tag()` with chunks of
+    /// `Count::MAX` elements. Slices that fit into a single message are sent by a single
+    /// operation. The message has to be received by `Source::receive_into_large_with_tag()`.
+    ///
+    /// # Examples
+    /// See `examples/send_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_large_with_tag<T>(&self, buf: &[T], tag: Tag)
+    where
+        T: Equivalence,
+    {
+        self.send_chunked_with_tag(buf, MAX_CHUNK_LEN, tag)
+    }
+
+    /// Blocking standard mode send operation for slices of any length
+    ///
+    /// Like `send_large_with_tag()` with the default tag.
+    ///
+    /// # Examples
+    /// See `examples/send_large.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    fn send_large<T>(&self, buf: &[T])
+    where
+        T: Equivalence,
+    {
+        self.send_large_with_tag(buf, Tag::default())
+    }
+
     /// Blocking buffered mode send operation
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
@@ -704,6 +1256,21 @@ pub trait Destination: AsCommunicator {
     ///
     /// Initiate sending the data in `buf` in standard mode.
     ///
+    /// `buf` stays borrowed for as long as `scope`, which the returned `Request` cannot outlive,
+    /// so it can neither be modified nor dropped before the request has completed:
+    ///
+    /// ```compile_fail
+    /// # use mpi::traits::*;
+    /// # let universe = mpi::initialize().unwrap();
+    /// # let world = universe.world();
+    /// let mut x = 1.0f64;
+    /// mpi::request::scope(|scope| {
+    ///     let request = world.this_process().immediate_send(scope, &x);
+    ///     x = 2.0;
+    ///     request.wait();
+    /// });
+    /// ```
+    ///
     /// # Examples
     /// See `examples/immediate.rs`
     ///
@@ -921,6 +1488,46 @@ impl Status {
     pub fn count<D: Datatype>(&self, d: D) -> Count {
         unsafe { with_uninitialized(|count| ffi::MPI_Get_count(&self.0, d.as_raw(), count)).1 }
     }
+
+    /// Number of predefined elements of the type contained in the message
+    ///
+    /// Unlike `count()`, this also counts the elements of a partially received instance of a
+    /// derived datatype, e.g. a message of five `i32` received with a datatype of three `i32`
+    /// contains five elements but no whole number of instances.
+    ///
+    /// # Examples
+    /// See `examples/receive_elements.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.11
+    pub fn elements<D: Datatype>(&self, d: D) -> Count {
+        unsafe { with_uninitialized(|count| ffi::MPI_Get_elements(&self.0, d.as_raw(), count)).1 }
+    }
+
+    /// A buffer of default values, e.g. zeros, with room for exactly the instances of `Msg`
+    /// contained in the message, e.g. to receive a message after it has been probed.
+    ///
+    /// # Examples
+    /// See `examples/make_buffer.rs`
+    ///
+    /// # Panics
+    ///
+    /// If the message does not contain a whole number of instances of `Msg`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.5
+    pub fn make_buffer<Msg>(&self) -> Vec<Msg>
+    where
+        Msg: Equivalence + Default + Clone,
+    {
+        let count = self
+            .count(Msg::equivalent_datatype())
+            .value_as()
+            .expect("Message element count cannot be expressed as a usize.");
+        vec![Msg::default(); count]
+    }
 }
 
 impl fmt::Debug for Status {
@@ -1084,24 +1691,13 @@ impl MatchedReceiveVec for (Message, Status) {
             .value_as()
             .expect("Message element count cannot be expressed as a usize.");
 
-        #[repr(transparent)]
-        struct UninitMsg<M>(MaybeUninit<M>);
-
-        unsafe impl<M: Equivalence> Equivalence for UninitMsg<M> {
-            type Out = M::Out;
-
-            fn equivalent_datatype() -> Self::Out {
-                M::equivalent_datatype()
-            }
-        }
-
         let mut res = (0..count)
-            .map(|_| UninitMsg::<Msg>(MaybeUninit::uninit()))
+            .map(|_| MaybeUninit::<Msg>::uninit())
             .collect::<Vec<_>>();
 
         let status = message.matched_receive_into(&mut res[..]);
 
-        let res = unsafe { transmute(res) };
+        let res = unsafe { transmute::<Vec<MaybeUninit<Msg>>, Vec<Msg>>(res) };
 
         (res, status)
     }
@@ -1183,6 +1779,10 @@ where
 /// simultaneously receives a message tagged `receivetag` from `source` into
 /// `buf`.
 ///
+/// `msg` and `buf` must not overlap, MPI does not allow its buffer arguments to alias. To send
+/// the contents of a buffer and replace them with the received message, use
+/// `send_receive_replace_into()` instead.
+///
 /// # Standard section(s)
 ///
 /// 3.10
@@ -1233,6 +1833,10 @@ where
 /// simultaneously receives a message from `source` into
 /// `buf`.
 ///
+/// `msg` and `buf` must not overlap, MPI does not allow its buffer arguments to alias. To send
+/// the contents of a buffer and replace them with the received message, use
+/// `send_receive_replace_into()` instead.
+///
 /// # Standard section(s)
 ///
 /// 3.10
@@ -1329,6 +1933,70 @@ where
     )
 }
 
+/// Exchanges the halo of `grid` with the neighbouring processes in `comm`.
+///
+/// For every side, the boundary of the interior next to that side is sent to the neighbour on
+/// that side, and the halo on the opposite side is received from the neighbour on the opposite
+/// side. `neighbours` holds the ranks of the neighbours in the order `North`, `South`, `East`,
+/// `West`, i.e. as returned by two calls to `CartesianCommunicator::shift()`. A side without a
+/// neighbour is `None`, its halo is left untouched.
+///
+/// All processes in `comm` must call this with the same kind of grid.
+///
+/// # Panics
+///
+/// If `grid` does not have `halo.rows() * halo.cols()` elements.
+///
+/// # Examples
+/// See `examples/halo_2d.rs`
+///
+/// # Standard section(s)
+///
+/// 3.10
+pub fn exchange_halo<C, T>(
+    comm: &C,
+    halo: &Halo2D<T>,
+    grid: &mut [T],
+    neighbours: [Option<Rank>; 4],
+) where
+    C: Communicator,
+    T: Equivalence,
+{
+    assert_eq!(
+        grid.len(),
+        halo.grid_len(),
+        "'grid' must have 'halo.rows() * halo.cols()' elements"
+    );
+
+    let proc_null = unsafe_extern_static!(ffi::RSMPI_PROC_NULL);
+    let neighbour = |side: Side| neighbours[side as usize].unwrap_or(proc_null);
+
+    // The boundary and halo regions are disjoint, so the whole grid is passed as both the send
+    // and the receive buffer.
+    let base = grid.as_mut_ptr();
+    for &side in Side::ALL.iter() {
+        let (opposite, tag) = (side.opposite(), side as Tag);
+        unsafe {
+            with_uninitialized(|status| {
+                ffi::MPI_Sendrecv(
+                    base.add(halo.boundary_offset(side)) as *const _,
+                    1,
+                    halo.datatype(side).as_raw(),
+                    neighbour(side),
+                    tag,
+                    base.add(halo.halo_offset(opposite)) as *mut _,
+                    1,
+                    halo.datatype(opposite).as_raw(),
+                    neighbour(opposite),
+                    tag,
+                    comm.as_raw(),
+                    status,
+                )
+            });
+        }
+    }
+}
+
 /// Will contain a value of type `T` received via a non-blocking receive operation.
 #[must_use]
 pub struct ReceiveFuture<T> {
@@ -15,7 +15,7 @@ pub unsafe trait AsRaw {
 
 unsafe impl<'a, T> AsRaw for &'a T
 where
-    T: 'a + AsRaw,
+    T: 'a + AsRaw + ?Sized,
 {
     type Raw = <T as AsRaw>::Raw;
     fn as_raw(&self) -> Self::Raw {
@@ -23,6 +23,16 @@ where
     }
 }
 
+unsafe impl<T> AsRaw for Box<T>
+where
+    T: AsRaw + ?Sized,
+{
+    type Raw = <T as AsRaw>::Raw;
+    fn as_raw(&self) -> Self::Raw {
+        (**self).as_raw()
+    }
+}
+
 /// A rust type than can provide a mutable pointer to a raw value understood by the MPI C API.
 pub unsafe trait AsRawMut: AsRaw {
     /// A mutable pointer to the raw value
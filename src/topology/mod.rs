@@ -651,11 +651,10 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
     where
         Buf: ?Sized + Buffer,
     {
-        let inbuf_dt = inbuf.as_datatype();
-
         let mut outbuf = vec![
             0;
-            self.pack_size(inbuf.count(), &inbuf_dt)
+            inbuf
+                .packed_size(self)
                 .value_as::<usize>()
                 .expect("MPI_Pack_size returned a negative buffer size!")
         ];
@@ -723,6 +722,94 @@ pub trait Communicator: AsRaw<Raw = MPI_Comm> {
         );
         position
     }
+
+    /// Iterates over the values of type `T` packed into `inbuf`, e.g. via `pack()` or repeated
+    /// calls to `pack_into()`, unpacking one value at a time until `inbuf` is exhausted.
+    ///
+    /// The iteration also ends if `MPI_Unpack()` returns an error code, which it only does instead
+    /// of aborting if the error handler `MPI_ERRORS_RETURN` has been set.
+    ///
+    /// # Panics
+    ///
+    /// If unpacking a value does not advance through `inbuf`, e.g. because `T` has a size of zero,
+    /// as the iteration would never end otherwise.
+    ///
+    /// # Examples
+    /// See `examples/pack.rs` and `examples/unpack_iter.rs`
+    ///
+    /// # Safety
+    ///
+    /// As for `unpack_into()`, `inbuf` must contain valid packed values of type `T`.
+    ///
+    /// # Standard Sections
+    ///
+    /// 4.2, see MPI_Unpack
+    unsafe fn unpack_iter<'a, T>(&'a self, inbuf: &'a [u8]) -> UnpackIter<'a, Self, T>
+    where
+        T: Equivalence,
+    {
+        UnpackIter {
+            comm: self,
+            inbuf,
+            position: 0,
+            datatype: T::equivalent_datatype(),
+        }
+    }
+}
+
+/// An iterator unpacking values of type `T` from a packed buffer
+///
+/// Returned by `Communicator::unpack_iter()`.
+pub struct UnpackIter<'a, C: ?Sized, T>
+where
+    T: Equivalence,
+{
+    comm: &'a C,
+    inbuf: &'a [u8],
+    position: Count,
+    datatype: <T as Equivalence>::Out,
+}
+
+impl<'a, C: ?Sized, T> Iterator for UnpackIter<'a, C, T>
+where
+    C: Communicator,
+    T: Equivalence,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.position >= self.inbuf.count() {
+            return None;
+        }
+
+        let start = self.position;
+        let mut value = MaybeUninit::<T>::uninit();
+        let code = unsafe {
+            ffi::MPI_Unpack(
+                self.inbuf.as_ptr() as *const _,
+                self.inbuf.count(),
+                &mut self.position,
+                value.as_mut_ptr() as *mut _,
+                1,
+                self.datatype.as_raw(),
+                self.comm.as_raw(),
+            )
+        };
+        let success: c_int = ffi::MPI_SUCCESS
+            .value_as()
+            .expect("MPI_SUCCESS does not fit into c_int");
+        if code != success {
+            // Nothing after a failed value can be unpacked reliably.
+            self.position = self.inbuf.count();
+            return None;
+        }
+        assert!(
+            self.position > start,
+            "Unpacking a value did not advance through the packed buffer"
+        );
+
+        Some(unsafe { value.assume_init() })
+    }
 }
 
 /// The relation between two communicators.